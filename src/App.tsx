@@ -1,23 +1,27 @@
 import { useEffect, useLayoutEffect, useMemo, useRef, useState, type CSSProperties, type ReactNode } from "react";
 import { createPortal } from "react-dom";
 import { listen } from "@tauri-apps/api/event";
-import { open as openDialog, save as saveDialog } from "@tauri-apps/api/dialog";
+import { ask as askDialog, open as openDialog, save as saveDialog } from "@tauri-apps/api/dialog";
 import { open as shellOpen } from "@tauri-apps/api/shell";
 import { convertFileSrc } from "@tauri-apps/api/tauri";
 import type {
   AccountDiagnostics,
   CreateInstanceFromModpackFileResult,
+  InstallModpackResult,
+  CurseforgeManualDownload,
   CreatorPreset,
   CreatorPresetEntry,
   CreatorPresetSettings,
   CurseforgeApiStatus,
   CurseforgeProjectDetail,
   DiscoverContentType,
+  DiscoverPreferences,
   DiscoverSearchHit,
   DiscoverSource,
   InstanceWorld,
   LaunchMethod,
   LauncherAccount,
+  LauncherCacheStats,
   LauncherImportSource,
   LauncherSettings,
   PresetApplyPreview,
@@ -32,14 +36,18 @@ import type {
   BeginMicrosoftLoginResult,
   MicrosoftLoginState,
   InstallPlanPreview,
+  InstallSizeEstimate,
   Instance,
   InstanceSettings,
   InstallProgressEvent,
+  NativeDownloadProgressEvent,
   InstalledMod,
   JavaRuntimeCandidate,
+  DryRunLaunchResult,
   LaunchResult,
   Loader,
   ModUpdateCheckResult,
+  CheckMissingDependenciesResult,
   SnapshotMeta,
 } from "./types";
 import {
@@ -48,23 +56,41 @@ import {
   checkModrinthUpdates,
   createInstance,
   createInstanceFromModpackFile,
+  inspectModpackFile,
   deleteInstance,
   exportPresetsJson,
   exportInstanceModsZip,
+  suggestExportFilename,
   getCurseforgeApiStatus,
+  setCurseforgeApiKey,
   getCurseforgeProjectDetail,
   getSelectedAccountDiagnostics,
   getLauncherSettings,
   importPresetsJson,
   importLocalModFile,
+  syncWorldDatapacks,
+  pruneMissingDatapackTargets,
+  checkMissingDependencies,
+  installMissingDependencies,
+  getDiscoverPreferences,
+  setDiscoverPreferences,
+  identifyLocalContent,
   importInstanceFromLauncher,
   installCurseforgeMod,
   installDiscoverContent,
   installModrinthMod,
   importProviderModpackTemplate,
+  installModrinthModpack,
+  installCurseforgeModpack,
+  cancelModpackInstall,
   previewPresetApply,
   applyPresetToInstance,
   launchInstance,
+  dryRunLaunch,
+  refreshLoaderVersion,
+  repairInstanceRuntime,
+  listLoaderVersions,
+  setInstanceLoaderVersion,
   listInstanceWorlds,
   listInstanceSnapshots,
   listLauncherAccounts,
@@ -77,16 +103,25 @@ import {
   rollbackInstanceWorldBackup,
   pollMicrosoftLogin,
   previewModrinthInstall,
+  estimateInstallSize,
   readInstanceLogs,
   readLocalImageDataUrl,
   openInstancePath,
+  listDiscoverCategories,
   searchDiscoverContent,
+  cancelDiscoverSearch,
   selectLauncherAccount,
   setLauncherSettings,
+  saveInstanceSettingsAsDefault,
   setInstanceIcon,
   setInstalledModEnabled,
   stopRunningInstance,
   detectJavaRuntimes,
+  runDiagnostics,
+  moveInstancesRoot,
+  getLauncherCacheStats,
+  openLauncherCache,
+  clearLauncherCache,
   updateAllModrinthMods,
   updateInstance,
 } from "./tauri";
@@ -129,6 +164,7 @@ type SchedulerCadence =
   | "weekly";
 type SchedulerAutoApplyMode = "never" | "opt_in_instances" | "all_instances";
 type SchedulerApplyScope = "scheduled_only" | "scheduled_and_manual";
+type SafeUpdateMode = "off" | "prompt" | "auto_rollback";
 
 type VersionItem = {
   id: string;
@@ -152,6 +188,45 @@ type InstanceLaunchStateEvent = {
   method?: string | null;
   status?: string | null;
   message?: string | null;
+  snapshot_id?: string | null;
+  rolled_back?: boolean;
+  /** Native launches only: machine-readable step plus progress within it. */
+  stage?: string | null;
+  current?: number | null;
+  total?: number | null;
+};
+
+type CrashAfterUpdatePrompt = {
+  instanceId: string;
+  snapshotId: string;
+  message: string;
+};
+
+type StateFileRecoveredEvent = {
+  path: string;
+  moved_to: string;
+  error: string;
+};
+
+type ScheduledUpdateCheckedEvent = {
+  instance_id: string;
+  instance_name: string;
+  checked_at: string;
+  checked_mods: number;
+  update_count: number;
+  updates: ModUpdateCheckResult["updates"];
+  applied_mods: number;
+  error?: string | null;
+};
+
+type ScheduledUpdateRunCompletedEvent = {
+  checked_at: string;
+  checked_instances: number;
+  skipped_running: number;
+  applied_instances: number;
+  applied_mods: number;
+  /** Instances whose updates were not applied because the pre-update snapshot failed. */
+  skipped_snapshot_failed?: number;
 };
 
 type LaunchHealthChecks = {
@@ -212,6 +287,42 @@ function mergeLaunchChecksFromMessage(
   };
 }
 
+const NATIVE_LAUNCH_STAGE_LABELS: Record<string, string> = {
+  preparing: "Preparing",
+  authenticating: "Auth",
+  resolving_loader: "Loader",
+  preparing_runtime: "Runtime",
+  installing_version: "Version",
+  downloading_assets: "Assets",
+  downloading_libraries: "Libraries",
+  launching: "Starting Java",
+};
+
+function launchStageProgressLabel(stage: {
+  label: string;
+  current?: number | null;
+  total?: number | null;
+} | null) {
+  if (!stage) return "";
+  const total = Number(stage.total ?? 0);
+  const current = Number(stage.current ?? 0);
+  if (!total || total <= 0) return stage.label;
+  const pct = Math.min(100, Math.max(0, Math.floor((current / total) * 100)));
+  return `${stage.label} ${pct}%`;
+}
+
+function nativeDownloadDetail(progress: NativeDownloadProgressEvent | null | undefined) {
+  if (!progress) return "";
+  if (progress.stage === "assets") {
+    const mb = (n?: number | null) => (Number(n ?? 0) / (1024 * 1024)).toFixed(1);
+    const files = `${progress.files_done} file${progress.files_done === 1 ? "" : "s"}`;
+    return progress.bytes_total ? `${files} · ${mb(progress.bytes_done)}/${mb(progress.bytes_total)} MB` : files;
+  }
+  return progress.files_total
+    ? `${progress.files_done}/${progress.files_total} libraries`
+    : `${progress.files_done} libraries`;
+}
+
 function launchStageBadgeLabel(status?: string | null, message?: string | null) {
   const state = String(status ?? "").toLowerCase();
   const text = String(message ?? "").toLowerCase();
@@ -313,9 +424,12 @@ function defaultInstanceSettings(): InstanceSettings {
     jvm_args: "",
     graphics_preset: "Balanced",
     enable_shaders: false,
+    shaders_managed: false,
     force_vsync: false,
     world_backup_interval_minutes: 10,
     world_backup_retention_count: 1,
+    account_id_override: null,
+    allow_fabric_on_quilt: false,
   };
 }
 
@@ -353,6 +467,7 @@ function normalizeInstanceSettings(input?: Partial<InstanceSettings> | null): In
     memory_mb: normalizedMemory,
     world_backup_interval_minutes: backupInterval,
     world_backup_retention_count: backupRetention,
+    account_id_override: String(merged.account_id_override ?? "").trim() || null,
   };
 }
 
@@ -404,6 +519,7 @@ function toLocalIconSrc(path?: string | null) {
   }
 }
 
+const LOCAL_IMAGE_MAX_DIMENSION = 256;
 const LOCAL_IMAGE_DATA_URL_CACHE = new Map<string, string>();
 const LOCAL_IMAGE_DATA_URL_PENDING = new Map<string, Promise<string | null>>();
 
@@ -419,9 +535,9 @@ async function resolveLocalImageDataUrl(path: string): Promise<string | null> {
   if (cached) return cached;
   const inFlight = LOCAL_IMAGE_DATA_URL_PENDING.get(value);
   if (inFlight) return inFlight;
-  const task = readLocalImageDataUrl({ path: value })
+  const task = readLocalImageDataUrl({ path: value, maxDimension: LOCAL_IMAGE_MAX_DIMENSION })
     .then((data) => {
-      const normalized = String(data ?? "").trim();
+      const normalized = String(data?.data_url ?? "").trim();
       if (!normalized) return null;
       LOCAL_IMAGE_DATA_URL_CACHE.set(value, normalized);
       return normalized;
@@ -607,6 +723,12 @@ function normalizeUpdateApplyScope(input?: string | null): SchedulerApplyScope {
   return "scheduled_only";
 }
 
+function normalizeSafeUpdateMode(input?: string | null): SafeUpdateMode {
+  const value = String(input ?? "").trim().toLowerCase();
+  if (value === "prompt" || value === "auto_rollback") return value;
+  return "off";
+}
+
 function updateCadenceLabel(cadence: SchedulerCadence): string {
   switch (cadence) {
     case "off":
@@ -792,6 +914,13 @@ function formatPercent(n: number | null | undefined) {
   return `${Math.max(0, Math.min(100, n)).toFixed(0)}%`;
 }
 
+function formatManualDownloads(list: CurseforgeManualDownload[]) {
+  return (
+    `${list.length} file${list.length === 1 ? "" : "s"} must be downloaded manually: ` +
+    list.map((m) => `${m.name} (${m.file_name || `file ${m.file_id}`}) from ${m.page_url} → ${m.target_dir}`).join("; ")
+  );
+}
+
 function formatDate(input: string | null | undefined) {
   const d = parseDateLike(input);
   if (!d) return input ?? "";
@@ -1236,6 +1365,11 @@ const DISCOVER_SOURCE_OPTIONS: { value: DiscoverSource; label: string }[] = [
   { value: "curseforge", label: "CurseForge" },
 ];
 
+const DISCOVER_PREFER_OPTIONS: { value: string; label: string }[] = [
+  { value: "modrinth", label: "Modrinth" },
+  { value: "curseforge", label: "CurseForge" },
+];
+
 const DISCOVER_CONTENT_OPTIONS: { value: DiscoverContentType; label: string }[] = [
   { value: "mods", label: "Mods" },
   { value: "shaderpacks", label: "Shaderpacks" },
@@ -1304,6 +1438,12 @@ const UPDATE_APPLY_SCOPE_OPTIONS: { value: SchedulerApplyScope; label: string }[
   { value: "scheduled_and_manual", label: "Scheduled + Check now" },
 ];
 
+const SAFE_UPDATE_MODE_OPTIONS: { value: SafeUpdateMode; label: string }[] = [
+  { value: "off", label: "Off" },
+  { value: "prompt", label: "Ask to roll back on crash" },
+  { value: "auto_rollback", label: "Roll back automatically on crash" },
+];
+
 const WORLD_BACKUP_INTERVAL_OPTIONS: { value: string; label: string }[] = [
   { value: "5", label: "Every 5 minutes" },
   { value: "10", label: "Every 10 minutes" },
@@ -2679,6 +2819,11 @@ export default function App() {
   const [instanceJvmArgsDraft, setInstanceJvmArgsDraft] = useState("");
   const [javaRuntimeCandidates, setJavaRuntimeCandidates] = useState<JavaRuntimeCandidate[]>([]);
   const [javaRuntimeBusy, setJavaRuntimeBusy] = useState(false);
+  const [dryRunReport, setDryRunReport] = useState<DryRunLaunchResult | null>(null);
+  const [dryRunBusy, setDryRunBusy] = useState(false);
+  const [loaderRefreshBusy, setLoaderRefreshBusy] = useState(false);
+  const [repairRuntimeBusy, setRepairRuntimeBusy] = useState(false);
+  const [loaderVersionOptions, setLoaderVersionOptions] = useState<string[]>([]);
 
   function openInstance(id: string) {
     setLibraryContextMenu(null);
@@ -2713,6 +2858,7 @@ export default function App() {
     setUpdateCheckCadence(normalizeUpdateCheckCadence(settings.update_check_cadence));
     setUpdateAutoApplyMode(normalizeUpdateAutoApplyMode(settings.update_auto_apply_mode));
     setUpdateApplyScope(normalizeUpdateApplyScope(settings.update_apply_scope));
+    setSafeUpdateMode(normalizeSafeUpdateMode(settings.safe_update_mode));
   }
 
   async function refreshAccountDiagnostics() {
@@ -2751,9 +2897,25 @@ export default function App() {
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, [route, instanceSettingsOpen, instanceSettingsSection, javaRuntimeCandidates.length, javaRuntimeBusy]);
 
+  useEffect(() => {
+    setLoaderVersionOptions([]);
+    if (!instanceSettingsOpen || instanceSettingsSection !== "installation" || !selected) return;
+    if (selected.loader !== "fabric" && selected.loader !== "forge") return;
+    let cancelled = false;
+    listLoaderVersions(selected.id)
+      .then((versions) => {
+        if (!cancelled) setLoaderVersionOptions(versions);
+      })
+      .catch(() => null);
+    return () => {
+      cancelled = true;
+    };
+  }, [instanceSettingsOpen, instanceSettingsSection, selected?.id, selected?.loader, selected?.mc_version]);
+
   const [presets, setPresets] = useState<UserPreset[]>([]);
   const [presetNameDraft, setPresetNameDraft] = useState("");
   const [presetBusy, setPresetBusy] = useState(false);
+  const [modpackInstallBusy, setModpackInstallBusy] = useState<string | null>(null);
   const [modpacksStudioTab, setModpacksStudioTab] = useState<"creator" | "templates" | "saved" | "config">("creator");
   const [creatorDraft, setCreatorDraft] = useState<UserPreset | null>(null);
   const [instanceWorlds, setInstanceWorlds] = useState<InstanceWorld[]>([]);
@@ -3031,6 +3193,80 @@ export default function App() {
     }
   }
 
+  async function onSaveInstanceSettingsAsDefault(inst: Instance) {
+    setBusy("instance-defaults");
+    setError(null);
+    try {
+      const settings = await saveInstanceSettingsAsDefault({ instanceId: inst.id });
+      setLauncherSettingsState(settings);
+      setInstallNotice(`New instances will start with ${inst.name}'s settings.`);
+    } catch (e: any) {
+      setError(e?.toString?.() ?? String(e));
+    } finally {
+      setBusy(null);
+    }
+  }
+
+  async function onDryRunLaunch(inst: Instance) {
+    setDryRunBusy(true);
+    setDryRunReport(null);
+    setError(null);
+    try {
+      const report = await dryRunLaunch(inst.id);
+      setDryRunReport(report);
+      setInstallNotice(report.ready ? "Launch check passed." : "Launch check found problems.");
+    } catch (e: any) {
+      setError(e?.toString?.() ?? String(e));
+    } finally {
+      setDryRunBusy(false);
+    }
+  }
+
+  async function onRefreshLoaderVersion(inst: Instance) {
+    setLoaderRefreshBusy(true);
+    setError(null);
+    try {
+      const version = await refreshLoaderVersion(inst.id);
+      setInstallNotice(version ? `Using ${inst.loader} ${version}.` : "Vanilla instances have no loader to update.");
+    } catch (e: any) {
+      setError(e?.toString?.() ?? String(e));
+    } finally {
+      setLoaderRefreshBusy(false);
+    }
+  }
+
+  async function onRepairInstanceRuntime(inst: Instance) {
+    setRepairRuntimeBusy(true);
+    setError(null);
+    try {
+      const res = await repairInstanceRuntime(inst.id);
+      const fixed = res.repaired.length + res.missing;
+      setInstallNotice(
+        fixed === 0
+          ? `Verified ${res.checked_files} Minecraft ${res.mc_version} files; nothing needed repair.`
+          : `Repaired ${res.repaired.length} corrupt and ${res.missing} missing Minecraft ${res.mc_version} file${fixed === 1 ? "" : "s"}.`
+      );
+    } catch (e: any) {
+      setError(e?.toString?.() ?? String(e));
+    } finally {
+      setRepairRuntimeBusy(false);
+    }
+  }
+
+  async function onSetLoaderVersion(inst: Instance, version: string | null) {
+    setInstanceSettingsBusy(true);
+    setError(null);
+    try {
+      const updated = await setInstanceLoaderVersion({ instanceId: inst.id, loaderVersion: version });
+      setInstances((prev) => prev.map((row) => (row.id === updated.id ? updated : row)));
+      setInstallNotice(version ? `Loader pinned to ${version}.` : "Loader will follow the latest build.");
+    } catch (e: any) {
+      setError(e?.toString?.() ?? String(e));
+    } finally {
+      setInstanceSettingsBusy(false);
+    }
+  }
+
   async function refreshJavaRuntimeCandidates() {
     setJavaRuntimeBusy(true);
     setLauncherErr(null);
@@ -3225,10 +3461,29 @@ export default function App() {
         inst = await createInstance({ name, mcVersion, loader, iconPath: createIconPath });
       } else if (createMode === "file") {
         if (!createPackFilePath) throw new Error("Pick a modpack archive first.");
+        const info = await inspectModpackFile(createPackFilePath);
+        const targetVersion = info.mc_version ?? (mcVersion || null);
+        if (!targetVersion) {
+          throw new Error(
+            "This archive doesn't say which Minecraft version it targets. Pick a game version under Custom, then import it again."
+          );
+        }
+        if (info.format === "overrides") {
+          const source = info.mc_version
+            ? "These were inferred from the bundled mods."
+            : "The version could not be inferred, so the one picked under Custom is used.";
+          const confirmed = await askDialog(
+            `This archive has no modpack index, so its files will be copied as-is without dependency resolution.\n\nCreate it as Minecraft ${targetVersion} with ${info.loader}? ${source}`,
+            { title: "Import plain modpack archive", type: "warning" }
+          );
+          if (!confirmed) return;
+        }
         const result: CreateInstanceFromModpackFileResult = await createInstanceFromModpackFile({
           filePath: createPackFilePath,
           name: name.trim() || undefined,
           iconPath: createIconPath,
+          mcVersion: targetVersion,
+          loader: info.loader,
         });
         inst = result.instance;
         if (result.warnings.length > 0) {
@@ -3248,9 +3503,15 @@ export default function App() {
           iconPath: createIconPath,
         });
         inst = result.instance;
-        setInstallNotice(
-          `Imported ${result.imported_files} file${result.imported_files === 1 ? "" : "s"} from launcher source.`
-        );
+        if (result.warnings.length > 0) {
+          setInstallNotice(
+            `Imported ${result.imported_files} file${result.imported_files === 1 ? "" : "s"} from launcher source with warnings: ${result.warnings.join(" | ")}`
+          );
+        } else {
+          setInstallNotice(
+            `Imported ${result.imported_files} file${result.imported_files === 1 ? "" : "s"} from launcher source.`
+          );
+        }
       }
       await refreshInstances();
 
@@ -3303,14 +3564,19 @@ export default function App() {
   const [q, setQ] = useState("");
   const [hits, setHits] = useState<DiscoverSearchHit[]>([]);
   const [totalHits, setTotalHits] = useState(0);
+  const [discoverEndReached, setDiscoverEndReached] = useState(false);
   const [offset, setOffset] = useState(0);
   const [limit, setLimit] = useState(20);
   const [index, setIndex] = useState<ModrinthIndex>("relevance");
   const [discoverSource, setDiscoverSource] = useState<DiscoverSource>("all");
+  const [discoverPreferredProvider, setDiscoverPreferredProvider] = useState<string>("modrinth");
   const [discoverContentType, setDiscoverContentType] = useState<DiscoverContentType>("mods");
   const [filterLoaders, setFilterLoaders] = useState<string[]>([]);
   const [filterVersion, setFilterVersion] = useState<string | null>(null);
   const [filterCategories, setFilterCategories] = useState<string[]>([]);
+  const discoverPrefsRef = useRef<Record<string, DiscoverPreferences>>({});
+  const [discoverPrefsLoaded, setDiscoverPrefsLoaded] = useState(false);
+  const [discoverCategoryGroups, setDiscoverCategoryGroups] = useState<CatGroup[] | null>(null);
   const [discoverErr, setDiscoverErr] = useState<string | null>(null);
   const [discoverBusy, setDiscoverBusy] = useState(false);
 
@@ -3350,7 +3616,20 @@ export default function App() {
   const [launchBusyInstanceId, setLaunchBusyInstanceId] = useState<string | null>(null);
   const [launchCancelBusyInstanceId, setLaunchCancelBusyInstanceId] = useState<string | null>(null);
   const [launchStageByInstance, setLaunchStageByInstance] = useState<
-    Record<string, { status: string; label: string; message: string; updated_at: number }>
+    Record<
+      string,
+      {
+        status: string;
+        label: string;
+        message: string;
+        updated_at: number;
+        current?: number | null;
+        total?: number | null;
+      }
+    >
+  >({});
+  const [nativeDownloadByInstance, setNativeDownloadByInstance] = useState<
+    Record<string, NativeDownloadProgressEvent>
   >({});
   const [launchProgressChecksByInstance, setLaunchProgressChecksByInstance] = useState<
     Record<string, LaunchHealthChecks>
@@ -3386,6 +3665,8 @@ export default function App() {
   const [updateCheckCadence, setUpdateCheckCadence] = useState<SchedulerCadence>("daily");
   const [updateAutoApplyMode, setUpdateAutoApplyMode] = useState<SchedulerAutoApplyMode>("never");
   const [updateApplyScope, setUpdateApplyScope] = useState<SchedulerApplyScope>("scheduled_only");
+  const [safeUpdateMode, setSafeUpdateMode] = useState<SafeUpdateMode>("off");
+  const [crashAfterUpdatePrompt, setCrashAfterUpdatePrompt] = useState<CrashAfterUpdatePrompt | null>(null);
   const [launcherSettings, setLauncherSettingsState] = useState<LauncherSettings | null>(null);
   const [launcherAccounts, setLauncherAccounts] = useState<LauncherAccount[]>([]);
   const [runningInstances, setRunningInstances] = useState<RunningInstance[]>([]);
@@ -3399,6 +3680,11 @@ export default function App() {
   const [javaPathDraft, setJavaPathDraft] = useState("");
   const [curseforgeApiStatus, setCurseforgeApiStatus] = useState<CurseforgeApiStatus | null>(null);
   const [curseforgeApiBusy, setCurseforgeApiBusy] = useState(false);
+  const [curseforgeApiKeyDraft, setCurseforgeApiKeyDraft] = useState("");
+  const [diagnosticsBusy, setDiagnosticsBusy] = useState(false);
+  const [instancesRootBusy, setInstancesRootBusy] = useState(false);
+  const [launcherCacheStats, setLauncherCacheStats] = useState<LauncherCacheStats | null>(null);
+  const [launcherCacheBusy, setLauncherCacheBusy] = useState(false);
   const [oauthClientIdDraft, setOauthClientIdDraft] = useState("");
   const [showAdvancedClientId, setShowAdvancedClientId] = useState(false);
   const [accountDiagnostics, setAccountDiagnostics] = useState<AccountDiagnostics | null>(() =>
@@ -3493,6 +3779,7 @@ export default function App() {
   const [updateCheck, setUpdateCheck] = useState<ModUpdateCheckResult | null>(null);
   const [updateBusy, setUpdateBusy] = useState(false);
   const [updateAllBusy, setUpdateAllBusy] = useState(false);
+  const [missingDeps, setMissingDeps] = useState<CheckMissingDependenciesResult | null>(null);
   const [updateErr, setUpdateErr] = useState<string | null>(null);
   const [scheduledUpdateEntriesByInstance, setScheduledUpdateEntriesByInstance] = useState<
     Record<string, ScheduledUpdateCheckEntry>
@@ -3526,6 +3813,8 @@ export default function App() {
   >({});
   const [installPlanPreviewBusy, setInstallPlanPreviewBusy] = useState<Record<string, boolean>>({});
   const [installPlanPreviewErr, setInstallPlanPreviewErr] = useState<Record<string, string>>({});
+  const [installSizeEstimate, setInstallSizeEstimate] = useState<Record<string, InstallSizeEstimate | string>>({});
+  const [installSizeBusy, setInstallSizeBusy] = useState<string | null>(null);
   const [snapshots, setSnapshots] = useState<SnapshotMeta[]>([]);
   const [snapshotsBusy, setSnapshotsBusy] = useState(false);
   const [rollbackBusy, setRollbackBusy] = useState(false);
@@ -3728,38 +4017,106 @@ export default function App() {
     }
   }, [scheduledUpdateLastRunAt]);
 
+  // Scheduled runs happen in the backend worker; mirror its results here.
   useEffect(() => {
-    if (!launcherSettings) return;
-    if (instances.length === 0) return;
-    const cadence = normalizeUpdateCheckCadence(updateCheckCadence);
-    if (cadence === "off") return;
-    const dueNow = () => {
-      if (scheduledUpdateRunningRef.current || document.hidden) return;
-      const lastMs = toTimestamp(scheduledUpdateLastRunAt ?? undefined);
-      const intervalMs = updateCadenceIntervalMs(cadence);
-      if (!Number.isFinite(lastMs) || Date.now() - lastMs >= intervalMs) {
-        runScheduledUpdateChecks("scheduled").catch(() => null);
+    const offChecked = listen<ScheduledUpdateCheckedEvent>("scheduled_update_checked", (event) => {
+      const payload = event.payload;
+      if (!payload?.instance_id) return;
+      setScheduledUpdateEntriesByInstance((prev) => ({
+        ...prev,
+        [payload.instance_id]: {
+          instance_id: payload.instance_id,
+          instance_name: payload.instance_name,
+          checked_at: payload.checked_at,
+          checked_mods: payload.checked_mods ?? 0,
+          update_count: payload.update_count ?? 0,
+          updates: payload.updates ?? [],
+          error: payload.error ?? null,
+        },
+      }));
+    });
+    const offCompleted = listen<ScheduledUpdateRunCompletedEvent>("scheduled_update_run_completed", (event) => {
+      const payload = event.payload;
+      if (!payload) return;
+      setScheduledUpdateLastRunAt(payload.checked_at);
+      if (payload.applied_instances > 0) {
+        setInstallNotice(
+          `Auto-applied ${payload.applied_mods} update${payload.applied_mods === 1 ? "" : "s"} across ${payload.applied_instances} instance${payload.applied_instances === 1 ? "" : "s"}.`
+        );
       }
-    };
-    dueNow();
-    const timer = window.setInterval(dueNow, 60_000);
-    const onVisibility = () => {
-      if (!document.hidden) dueNow();
-    };
-    document.addEventListener("visibilitychange", onVisibility);
+      const skipped = payload.skipped_snapshot_failed ?? 0;
+      if (skipped > 0) {
+        setInstallNotice(
+          `Skipped auto-updates for ${skipped} instance${skipped === 1 ? "" : "s"} because a snapshot could not be created first.`
+        );
+      }
+    });
     return () => {
-      window.clearInterval(timer);
-      document.removeEventListener("visibilitychange", onVisibility);
+      offChecked.then((unlisten) => unlisten()).catch(() => null);
+      offCompleted.then((unlisten) => unlisten()).catch(() => null);
     };
-    // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, [
-    launcherSettings,
-    instances,
-    updateCheckCadence,
-    scheduledUpdateLastRunAt,
-    updateAutoApplyMode,
-    updateApplyScope,
-  ]);
+  }, []);
+
+  async function onCopyDiagnostics() {
+    setDiagnosticsBusy(true);
+    setLauncherErr(null);
+    try {
+      const report = await runDiagnostics();
+      await navigator.clipboard.writeText(JSON.stringify(report, null, 2));
+      setInstallNotice("Diagnostics copied to clipboard.");
+    } catch (e: any) {
+      setLauncherErr(e?.toString?.() ?? String(e));
+    } finally {
+      setDiagnosticsBusy(false);
+    }
+  }
+
+  async function onMoveInstancesRoot(resetToDefault = false) {
+    setLauncherErr(null);
+    try {
+      let targetPath: string | undefined;
+      if (!resetToDefault) {
+        const picked = await openDialog({ directory: true, multiple: false });
+        if (!picked || Array.isArray(picked)) return;
+        targetPath = picked;
+      }
+      setInstancesRootBusy(true);
+      const result = await moveInstancesRoot({ targetPath });
+      setLauncherSettingsState(await getLauncherSettings());
+      await refreshInstances();
+      setInstallNotice(result.message);
+      if (result.warning) setLauncherErr(result.warning);
+    } catch (e: any) {
+      setLauncherErr(e?.toString?.() ?? String(e));
+    } finally {
+      setInstancesRootBusy(false);
+    }
+  }
+
+  async function refreshLauncherCacheStats() {
+    setLauncherCacheBusy(true);
+    try {
+      setLauncherCacheStats(await getLauncherCacheStats());
+    } catch (e: any) {
+      setLauncherErr(e?.toString?.() ?? String(e));
+    } finally {
+      setLauncherCacheBusy(false);
+    }
+  }
+
+  async function onClearLauncherCache() {
+    setLauncherErr(null);
+    setLauncherCacheBusy(true);
+    try {
+      const result = await clearLauncherCache();
+      setInstallNotice(result.message);
+      setLauncherCacheStats(await getLauncherCacheStats());
+    } catch (e: any) {
+      setLauncherErr(e?.toString?.() ?? String(e));
+    } finally {
+      setLauncherCacheBusy(false);
+    }
+  }
 
   async function refreshCurseforgeApiStatus() {
     setCurseforgeApiBusy(true);
@@ -3775,6 +4132,21 @@ export default function App() {
     }
   }
 
+  async function onSaveCurseforgeApiKey(clear = false) {
+    setCurseforgeApiBusy(true);
+    setLauncherErr(null);
+    try {
+      const status = await setCurseforgeApiKey({ apiKey: clear ? "" : curseforgeApiKeyDraft });
+      setCurseforgeApiStatus(status);
+      setCurseforgeApiKeyDraft("");
+    } catch (e: any) {
+      const msg = e?.toString?.() ?? String(e);
+      setLauncherErr(msg);
+    } finally {
+      setCurseforgeApiBusy(false);
+    }
+  }
+
   useEffect(() => {
     if (route !== "settings") return;
     if (curseforgeApiStatus || curseforgeApiBusy) return;
@@ -4070,7 +4442,60 @@ export default function App() {
     };
   }
 
+  function applyDiscoverPreferences(contentType: DiscoverContentType) {
+    const saved = discoverPrefsRef.current[contentType];
+    setFilterLoaders(Array.isArray(saved?.loaders) ? saved.loaders : []);
+    setFilterCategories(Array.isArray(saved?.categories) ? saved.categories : []);
+    setFilterVersion(typeof saved?.game_version === "string" ? saved.game_version : null);
+    if (typeof saved?.index === "string" && saved.index) setIndex(saved.index as ModrinthIndex);
+  }
+
+  useEffect(() => {
+    let cancelled = false;
+    getDiscoverPreferences()
+      .then((prefs) => {
+        if (cancelled) return;
+        discoverPrefsRef.current = prefs ?? {};
+        applyDiscoverPreferences(discoverContentType);
+      })
+      .catch(() => null)
+      .finally(() => {
+        if (!cancelled) setDiscoverPrefsLoaded(true);
+      });
+    return () => {
+      cancelled = true;
+    };
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, []);
+
+  useEffect(() => {
+    if (!discoverPrefsLoaded) return;
+    const prefs: DiscoverPreferences = {
+      loaders: filterLoaders,
+      categories: filterCategories,
+      game_version: filterVersion,
+      index,
+    };
+    discoverPrefsRef.current = { ...discoverPrefsRef.current, [discoverContentType]: prefs };
+    const timer = window.setTimeout(() => {
+      setDiscoverPreferences({ contentType: discoverContentType, preferences: prefs }).catch(() => null);
+    }, 500);
+    return () => window.clearTimeout(timer);
+  }, [discoverPrefsLoaded, discoverContentType, filterLoaders, filterCategories, filterVersion, index]);
+
+  const discoverProviderPriority =
+    discoverPreferredProvider === "curseforge" ? ["curseforge", "modrinth"] : ["modrinth", "curseforge"];
+
+  const discoverSearchSeqRef = useRef(0);
+  const discoverSearchIdsRef = useRef<string[]>([]);
+
   async function runSearch(newOffset: number) {
+    const seq = ++discoverSearchSeqRef.current;
+    for (const searchId of discoverSearchIdsRef.current) {
+      cancelDiscoverSearch({ searchId }).catch(() => null);
+    }
+    const searchId = `discover-${seq}`;
+    discoverSearchIdsRef.current = [searchId, `${searchId}-modpacks`];
     setDiscoverErr(null);
     setDiscoverBusy(true);
     try {
@@ -4087,6 +4512,8 @@ export default function App() {
             offset: 0,
             source: discoverSource,
             contentType: "datapacks",
+            providerPriority: discoverProviderPriority,
+            searchId,
           }).catch(() => ({ hits: [], total_hits: 0, offset: 0, limit: windowLimit })),
           searchDiscoverContent({
             query: q,
@@ -4098,8 +4525,11 @@ export default function App() {
             offset: 0,
             source: discoverSource,
             contentType: "modpacks",
+            providerPriority: discoverProviderPriority,
+            searchId: `${searchId}-modpacks`,
           }).catch(() => ({ hits: [], total_hits: 0, offset: 0, limit: windowLimit })),
         ]);
+        if (seq !== discoverSearchSeqRef.current) return;
         const merged = [...datapacksRes.hits, ...modpacksRes.hits];
         merged.sort((a, b) => {
           if (index === "downloads") return (b.downloads ?? 0) - (a.downloads ?? 0);
@@ -4112,6 +4542,7 @@ export default function App() {
         );
         setHits(merged.slice(newOffset, newOffset + limit));
         setTotalHits(mergedTotalHits);
+        setDiscoverEndReached(newOffset + limit >= merged.length);
         setOffset(newOffset);
       } else {
         const res = await searchDiscoverContent({
@@ -4124,15 +4555,19 @@ export default function App() {
           offset: newOffset,
           source: discoverSource,
           contentType: discoverContentType,
+          providerPriority: discoverProviderPriority,
+          searchId,
         });
+        if (res.cancelled || seq !== discoverSearchSeqRef.current) return;
         setHits(res.hits);
         setTotalHits(res.total_hits);
+        setDiscoverEndReached(Boolean(res.end_reached));
         setOffset(res.offset);
       }
     } catch (e: any) {
-      setDiscoverErr(e?.toString?.() ?? String(e));
+      if (seq === discoverSearchSeqRef.current) setDiscoverErr(e?.toString?.() ?? String(e));
     } finally {
-      setDiscoverBusy(false);
+      if (seq === discoverSearchSeqRef.current) setDiscoverBusy(false);
     }
   }
 
@@ -4140,7 +4575,39 @@ export default function App() {
     if (route !== "discover") return;
     runSearch(0);
     // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, [route, index, limit, filterLoaders, filterVersion, filterCategories, discoverSource, discoverContentType]);
+  }, [
+    route,
+    index,
+    limit,
+    filterLoaders,
+    filterVersion,
+    filterCategories,
+    discoverSource,
+    discoverPreferredProvider,
+    discoverContentType,
+  ]);
+
+  useEffect(() => {
+    if (route !== "discover") return;
+    let cancelled = false;
+    listDiscoverCategories({ contentType: discoverContentType })
+      .then((cats) => {
+        if (cancelled) return;
+        const byHeader = new Map<string, Cat[]>();
+        for (const c of cats) {
+          const header = humanizeToken(c.header || "categories");
+          byHeader.set(header, [...(byHeader.get(header) ?? []), { id: c.slug, label: c.name }]);
+        }
+        const groups = Array.from(byHeader, ([group, items]) => ({ group, items }));
+        setDiscoverCategoryGroups(groups.length ? groups : null);
+      })
+      .catch(() => {
+        if (!cancelled) setDiscoverCategoryGroups(null);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [route, discoverContentType]);
 
   async function runTemplateSearch(newOffset: number, queryOverride?: string) {
     setTemplateErr(null);
@@ -4236,6 +4703,40 @@ export default function App() {
     setInstallNotice(`Added "${hit.title}" to creator draft.`);
   }
 
+  async function onInstallModpack(pack: { source: "modrinth" | "curseforge"; projectId: string; title: string }) {
+    setModpackInstallBusy(pack.projectId);
+    setError(null);
+    try {
+      const input = { projectId: pack.projectId, name: pack.title };
+      const result: InstallModpackResult =
+        pack.source === "curseforge" ? await installCurseforgeModpack(input) : await installModrinthModpack(input);
+      await refreshInstances();
+      setSelectedId(result.instance.id);
+      const summary = `Installed "${result.instance.name}" with ${result.installed_files} file${result.installed_files === 1 ? "" : "s"} and ${result.imported_files} override${result.imported_files === 1 ? "" : "s"}.`;
+      setInstallNotice(result.warnings.length > 0 ? `${summary} Warnings: ${result.warnings.join(" | ")}` : summary);
+      if (result.manual_downloads.length > 0) {
+        setError(formatManualDownloads(result.manual_downloads));
+      }
+    } catch (e: any) {
+      const message = e?.toString?.() ?? String(e);
+      if (message.includes("Modpack install was cancelled")) {
+        setInstallNotice(`Cancelled installing "${pack.title}".`);
+      } else {
+        setError(message);
+      }
+    } finally {
+      setModpackInstallBusy(null);
+    }
+  }
+
+  async function onCancelModpackInstall(projectId: string) {
+    try {
+      await cancelModpackInstall({ projectId });
+    } catch (e: any) {
+      setError(e?.toString?.() ?? String(e));
+    }
+  }
+
   async function importTemplateFromHit(hit: DiscoverSearchHit, inst: Instance | null) {
     setPresetBusy(true);
     setError(null);
@@ -4381,6 +4882,28 @@ export default function App() {
     setInstallInstanceQuery("");
   }
 
+  useEffect(() => {
+    setInstallSizeEstimate({});
+  }, [installTarget?.projectId, installTarget?.source, installTarget?.contentType]);
+
+  async function onEstimateInstallSize(inst: Instance) {
+    if (!installTarget) return;
+    setInstallSizeBusy(inst.id);
+    try {
+      const estimate = await estimateInstallSize({
+        instanceId: inst.id,
+        source: installTarget.source === "curseforge" ? "curseforge" : "modrinth",
+        projectId: installTarget.projectId,
+        contentType: installTarget.contentType,
+      });
+      setInstallSizeEstimate((prev) => ({ ...prev, [inst.id]: estimate }));
+    } catch (e: any) {
+      setInstallSizeEstimate((prev) => ({ ...prev, [inst.id]: e?.toString?.() ?? String(e) }));
+    } finally {
+      setInstallSizeBusy(null);
+    }
+  }
+
   useEffect(() => {
     if (!installTarget) {
       setInstallPlanPreview({});
@@ -4711,6 +5234,9 @@ export default function App() {
       setInstallNotice(
         `${applyResult.message} Installed ${applyResult.installed_entries}, skipped ${applyResult.skipped_entries}, failed ${applyResult.failed_entries}.${byTypeText ? ` (${byTypeText})` : ""}`
       );
+      if (applyResult.manual_downloads.length > 0) {
+        setError(formatManualDownloads(applyResult.manual_downloads));
+      }
     } catch (e: any) {
       setError(e?.toString?.() ?? String(e));
     } finally {
@@ -4937,12 +5463,14 @@ export default function App() {
       setImportingInstanceId(inst.id);
       let successCount = 0;
       const failedPaths: string[] = [];
+      const loaderWarnings: string[] = [];
       for (const filePath of filePaths) {
         try {
-          await importLocalModFile({
+          const res = await importLocalModFile({
             instanceId: inst.id,
             filePath,
           });
+          if (res.warning) loaderWarnings.push(res.warning);
           successCount += 1;
         } catch {
           failedPaths.push(filePath);
@@ -4954,6 +5482,9 @@ export default function App() {
           `Added ${successCount} mod file${successCount === 1 ? "" : "s"} from your computer.`
         );
       }
+      if (loaderWarnings.length > 0) {
+        setModsErr(loaderWarnings.join(" "));
+      }
       if (failedPaths.length > 0) {
         const short = failedPaths
           .slice(0, 3)
@@ -5097,18 +5628,29 @@ export default function App() {
     }
   }
 
-  async function onExportModsZip(inst: Instance) {
+  async function onExportModsZip(inst: Instance, serverOnly = false) {
     setLauncherErr(null);
     setInstallNotice(null);
     try {
-      const suggested = `${inst.name.replace(/\s+/g, "-") || "instance"}-mods.zip`;
+      const suggested = await suggestExportFilename({ instanceId: inst.id, serverOnly })
+        .then((res) => res.path)
+        .catch(() => `${inst.name.replace(/\s+/g, "-") || "instance"}-${serverOnly ? "server-mods" : "mods"}.zip`);
       const savePath = await saveDialog({
         defaultPath: suggested,
         filters: [{ name: "Zip archive", extensions: ["zip"] }],
       });
       if (!savePath || Array.isArray(savePath)) return;
-      const out = await exportInstanceModsZip({ instanceId: inst.id, outputPath: savePath });
-      setInstallNotice(`Exported ${out.files_count} file(s) to ${out.output_path}`);
+      const out = await exportInstanceModsZip({ instanceId: inst.id, outputPath: savePath, serverOnly });
+      const notes: string[] = [];
+      if (out.excluded_client_only?.length) {
+        notes.push(`skipped ${out.excluded_client_only.length} client-only mod(s)`);
+      }
+      if (out.unknown_side?.length) {
+        notes.push(`${out.unknown_side.length} mod(s) with unknown server support: ${out.unknown_side.join(", ")}`);
+      }
+      setInstallNotice(
+        `Exported ${out.files_count} file(s) to ${out.output_path}${notes.length ? ` (${notes.join("; ")})` : ""}`
+      );
     } catch (e: any) {
       setLauncherErr(e?.toString?.() ?? String(e));
     }
@@ -5144,6 +5686,7 @@ export default function App() {
     cadence?: SchedulerCadence;
     autoApplyMode?: SchedulerAutoApplyMode;
     applyScope?: SchedulerApplyScope;
+    safeUpdateMode?: SafeUpdateMode;
   }) {
     setUpdatePrefsBusy(true);
     setScheduledUpdateErr(null);
@@ -5152,11 +5695,13 @@ export default function App() {
         updateCheckCadence: next.cadence ?? updateCheckCadence,
         updateAutoApplyMode: next.autoApplyMode ?? updateAutoApplyMode,
         updateApplyScope: next.applyScope ?? updateApplyScope,
+        safeUpdateMode: next.safeUpdateMode ?? safeUpdateMode,
       });
       setLauncherSettingsState(settings);
       setUpdateCheckCadence(normalizeUpdateCheckCadence(settings.update_check_cadence));
       setUpdateAutoApplyMode(normalizeUpdateAutoApplyMode(settings.update_auto_apply_mode));
       setUpdateApplyScope(normalizeUpdateApplyScope(settings.update_apply_scope));
+      setSafeUpdateMode(normalizeSafeUpdateMode(settings.safe_update_mode));
     } catch (e: any) {
       const msg = e?.toString?.() ?? String(e);
       setScheduledUpdateErr(msg);
@@ -5166,6 +5711,16 @@ export default function App() {
     }
   }
 
+  async function onTogglePrismAccountPassthrough(enabled: boolean) {
+    setLauncherErr(null);
+    try {
+      const next = await setLauncherSettings({ prismAccountPassthrough: enabled });
+      setLauncherSettingsState(next);
+    } catch (e: any) {
+      setLauncherErr(e?.toString?.() ?? String(e));
+    }
+  }
+
   async function onSaveLauncherPrefs() {
     setLauncherBusy(true);
     setLauncherErr(null);
@@ -5179,6 +5734,7 @@ export default function App() {
       setUpdateCheckCadence(normalizeUpdateCheckCadence(next.update_check_cadence));
       setUpdateAutoApplyMode(normalizeUpdateAutoApplyMode(next.update_auto_apply_mode));
       setUpdateApplyScope(normalizeUpdateApplyScope(next.update_apply_scope));
+      setSafeUpdateMode(normalizeSafeUpdateMode(next.safe_update_mode));
       setInstallNotice("Launcher settings saved.");
     } catch (e: any) {
       setLauncherErr(e?.toString?.() ?? String(e));
@@ -5237,6 +5793,7 @@ export default function App() {
       setUpdateCheckCadence(normalizeUpdateCheckCadence(settings.update_check_cadence));
       setUpdateAutoApplyMode(normalizeUpdateAutoApplyMode(settings.update_auto_apply_mode));
       setUpdateApplyScope(normalizeUpdateApplyScope(settings.update_apply_scope));
+      setSafeUpdateMode(normalizeSafeUpdateMode(settings.safe_update_mode));
       await refreshAccountDiagnostics();
       setInstallNotice("Launcher account selected.");
     } catch (e: any) {
@@ -5257,6 +5814,7 @@ export default function App() {
       setUpdateCheckCadence(normalizeUpdateCheckCadence(settings.update_check_cadence));
       setUpdateAutoApplyMode(normalizeUpdateAutoApplyMode(settings.update_auto_apply_mode));
       setUpdateApplyScope(normalizeUpdateApplyScope(settings.update_apply_scope));
+      setSafeUpdateMode(normalizeSafeUpdateMode(settings.safe_update_mode));
       await refreshAccountDiagnostics();
       setInstallNotice("Microsoft account disconnected.");
     } catch (e: any) {
@@ -5393,6 +5951,92 @@ export default function App() {
     }
   }
 
+  async function onSyncWorldDatapacks(inst: Instance) {
+    setUpdateBusy(true);
+    setUpdateErr(null);
+    try {
+      const pruned = await pruneMissingDatapackTargets({ instanceId: inst.id });
+      const res = await syncWorldDatapacks({ instanceId: inst.id });
+      await refreshInstalledMods(inst.id);
+      const parts: string[] = [];
+      if (pruned.removed.length > 0) parts.push(`${pruned.removed.length} removed with deleted worlds`);
+      if (pruned.pruned.length > 0) parts.push(`${pruned.pruned.length} unlinked from deleted worlds`);
+      if (res.added.length > 0) parts.push(`${res.added.length} new`);
+      if (res.linked.length > 0) parts.push(`${res.linked.length} found in more worlds`);
+      if (res.missing.length > 0) parts.push(`${res.missing.length} missing`);
+      setInstallNotice(
+        parts.length === 0
+          ? `Datapacks match ${res.worlds_scanned} world${res.worlds_scanned === 1 ? "" : "s"}.`
+          : `Datapack scan: ${parts.join(", ")}.`
+      );
+    } catch (e: any) {
+      setUpdateErr(e?.toString?.() ?? String(e));
+    } finally {
+      setUpdateBusy(false);
+    }
+  }
+
+  async function onCheckMissingDependencies(inst: Instance) {
+    setUpdateBusy(true);
+    setUpdateErr(null);
+    try {
+      const res = await checkMissingDependencies({ instanceId: inst.id });
+      setMissingDeps(res);
+      if (res.missing.length === 0) {
+        setInstallNotice(`All required dependencies of ${res.checked} mod${res.checked === 1 ? "" : "s"} are installed.`);
+      }
+    } catch (e: any) {
+      setUpdateErr(e?.toString?.() ?? String(e));
+    } finally {
+      setUpdateBusy(false);
+    }
+  }
+
+  async function onInstallMissingDependencies(inst: Instance) {
+    if (!missingDeps || missingDeps.instance_id !== inst.id) return;
+    const fixable = missingDeps.missing.filter((dep) => dep.status !== "unverified");
+    if (fixable.length === 0) return;
+    setUpdateBusy(true);
+    setUpdateErr(null);
+    try {
+      const res = await installMissingDependencies({
+        instanceId: inst.id,
+        dependencies: fixable.map((dep) => ({ project_id: dep.project_id, name: dep.name })),
+      });
+      await refreshInstalledMods(inst.id);
+      setMissingDeps(null);
+      const fixed = res.installed.length + res.enabled.length;
+      if (res.failed.length > 0) {
+        setUpdateErr(`Could not fix ${res.failed.length} dependenc${res.failed.length === 1 ? "y" : "ies"}: ${res.failed.join("; ")}`);
+      }
+      if (fixed > 0) {
+        setInstallNotice(`Fixed ${fixed} missing dependenc${fixed === 1 ? "y" : "ies"}.`);
+      }
+    } catch (e: any) {
+      setUpdateErr(e?.toString?.() ?? String(e));
+    } finally {
+      setUpdateBusy(false);
+    }
+  }
+
+  async function onIdentifyLocalContent(inst: Instance) {
+    setUpdateBusy(true);
+    setUpdateErr(null);
+    try {
+      const res = await identifyLocalContent({ instanceId: inst.id });
+      await refreshInstalledMods(inst.id);
+      setInstallNotice(
+        res.checked === 0
+          ? "No local files to identify."
+          : `Identified ${res.identified.length} of ${res.checked} local file${res.checked === 1 ? "" : "s"}.`
+      );
+    } catch (e: any) {
+      setUpdateErr(e?.toString?.() ?? String(e));
+    } finally {
+      setUpdateBusy(false);
+    }
+  }
+
   async function onUpdateAll(inst: Instance) {
     setUpdateAllBusy(true);
     setUpdateErr(null);
@@ -5469,6 +6113,17 @@ export default function App() {
     }
   }, [instanceContentType]);
 
+  useEffect(() => {
+    const off = listen<NativeDownloadProgressEvent>("native_download_progress", (event) => {
+      const payload = event.payload;
+      if (!payload?.instance_id) return;
+      setNativeDownloadByInstance((prev) => ({ ...prev, [payload.instance_id]: payload }));
+    });
+    return () => {
+      off.then((unlisten) => unlisten()).catch(() => null);
+    };
+  }, []);
+
   useEffect(() => {
     const off = listen<InstallProgressEvent>("mod_install_progress", (event) => {
       const payload = event.payload;
@@ -5480,6 +6135,19 @@ export default function App() {
     };
   }, []);
 
+  useEffect(() => {
+    const off = listen<StateFileRecoveredEvent>("state_file_recovered", (event) => {
+      const payload = event.payload;
+      if (!payload) return;
+      setInstallNotice(
+        `A corrupt state file was reset. The damaged copy was kept at ${payload.moved_to}`
+      );
+    });
+    return () => {
+      off.then((unlisten) => unlisten()).catch(() => null);
+    };
+  }, []);
+
   useEffect(() => {
     const off = listen<InstanceLaunchStateEvent>("instance_launch_state", (event) => {
       const payload = event.payload;
@@ -5499,12 +6167,25 @@ export default function App() {
             ),
           }));
         }
-        if (status === "running" || status === "stopped" || status === "exited") {
+        if (
+          status !== "starting" ||
+          (payload.stage !== "downloading_assets" && payload.stage !== "downloading_libraries")
+        ) {
+          setNativeDownloadByInstance((prev) => {
+            if (!prev[instanceId]) return prev;
+            const next = { ...prev };
+            delete next[instanceId];
+            return next;
+          });
+        }
+        if (status === "running" || status === "stopped" || status === "exited" || status === "unknown") {
           setLaunchBusyInstanceId((prev) => (prev === instanceId ? null : prev));
           setLaunchCancelBusyInstanceId((prev) => (prev === instanceId ? null : prev));
         }
         if (status === "starting" || status === "running") {
-          const label = launchStageBadgeLabel(status, message);
+          const stageKey = String(payload.stage ?? "").toLowerCase();
+          const label =
+            (status === "starting" && NATIVE_LAUNCH_STAGE_LABELS[stageKey]) || launchStageBadgeLabel(status, message);
           setLaunchStageByInstance((prev) => ({
             ...prev,
             [instanceId]: {
@@ -5512,9 +6193,11 @@ export default function App() {
               label: label || (status === "running" ? "Running" : "Launching"),
               message,
               updated_at: Date.now(),
+              current: payload.current ?? null,
+              total: payload.total ?? null,
             },
           }));
-        } else if (status === "stopped" || status === "exited") {
+        } else if (status === "stopped" || status === "exited" || status === "unknown") {
           setLaunchStageByInstance((prev) => {
             const next = { ...prev };
             delete next[instanceId];
@@ -5605,8 +6288,16 @@ export default function App() {
         } else if (message) {
           setLauncherErr(message);
         }
-      } else if (status === "stopped") {
+      } else if (status === "stopped" || status === "unknown") {
         if (message) setInstallNotice(message);
+      } else if (status === "crashed_after_update") {
+        if (payload.rolled_back) {
+          setInstallNotice(message);
+        } else if (instanceId && payload.snapshot_id) {
+          setCrashAfterUpdatePrompt({ instanceId, snapshotId: payload.snapshot_id, message });
+        } else if (message) {
+          setLauncherErr(message);
+        }
       }
 
       listRunningInstances()
@@ -5640,6 +6331,7 @@ export default function App() {
           setUpdateCheckCadence(normalizeUpdateCheckCadence(settings.update_check_cadence));
           setUpdateAutoApplyMode(normalizeUpdateAutoApplyMode(settings.update_auto_apply_mode));
           setUpdateApplyScope(normalizeUpdateApplyScope(settings.update_apply_scope));
+          setSafeUpdateMode(normalizeSafeUpdateMode(settings.safe_update_mode));
           refreshAccountDiagnostics().catch(() => null);
           setInstallNotice(state.message ?? "Microsoft account connected.");
           setMsLoginSessionId(null);
@@ -6834,6 +7526,15 @@ export default function App() {
                     ]}
                   />
                 </div>
+                <label className="toggleRow" style={{ marginTop: 8 }}>
+                  <input
+                    type="checkbox"
+                    checked={launcherSettings?.prism_account_passthrough ?? true}
+                    onChange={(event) => void onTogglePrismAccountPassthrough(event.target.checked)}
+                  />
+                  <span className="togglePill" />
+                  <span>Launch Prism with the selected account</span>
+                </label>
               </div>
 
               <div>
@@ -6903,7 +7604,29 @@ export default function App() {
               <div>
                 <div className="settingTitle">CurseForge API (Setup)</div>
                 <div className="settingSub">
-                  CurseForge search/install requires an official API key. This app reads `MPM_CURSEFORGE_API_KEY` (or `CURSEFORGE_API_KEY`) from your environment.
+                  CurseForge search/install requires an official API key. Save one below (stored encrypted), or set `MPM_CURSEFORGE_API_KEY` (or `CURSEFORGE_API_KEY`) in your environment.
+                </div>
+                <div className="row">
+                  <input
+                    className="input"
+                    type="password"
+                    value={curseforgeApiKeyDraft}
+                    onChange={(e) => setCurseforgeApiKeyDraft(e.target.value)}
+                    placeholder="Paste CurseForge API key"
+                    disabled={curseforgeApiBusy}
+                  />
+                  <button
+                    className="btn primary"
+                    onClick={() => void onSaveCurseforgeApiKey()}
+                    disabled={curseforgeApiBusy || !curseforgeApiKeyDraft.trim()}
+                  >
+                    Validate & save
+                  </button>
+                  {curseforgeApiStatus?.env_var === "launcher settings" ? (
+                    <button className="btn" onClick={() => void onSaveCurseforgeApiKey(true)} disabled={curseforgeApiBusy}>
+                      Remove saved key
+                    </button>
+                  ) : null}
                 </div>
                 <div className="row">
                   <button className="btn" onClick={() => void refreshCurseforgeApiStatus()} disabled={curseforgeApiBusy}>
@@ -6949,6 +7672,72 @@ export default function App() {
                 ) : null}
               </div>
 
+              <div>
+                <div className="settingTitle">Instances folder</div>
+                <div className="settingSub">
+                  {launcherSettings?.instances_root_override
+                    ? `Stored in ${launcherSettings.instances_root_override}.`
+                    : "Stored in the app data folder."}{" "}
+                  Moving copies every instance to the new folder and updates their paths.
+                </div>
+                <div className="row">
+                  <button className="btn" onClick={() => void onMoveInstancesRoot()} disabled={instancesRootBusy}>
+                    {instancesRootBusy ? "Moving…" : "Move instances folder…"}
+                  </button>
+                  {launcherSettings?.instances_root_override ? (
+                    <button className="btn" onClick={() => void onMoveInstancesRoot(true)} disabled={instancesRootBusy}>
+                      Move back to default
+                    </button>
+                  ) : null}
+                </div>
+              </div>
+
+              <div>
+                <div className="settingTitle">Game file cache</div>
+                <div className="settingSub">
+                  Assets, libraries and versions shared by every native instance. Cleared files are downloaded again on next launch.
+                </div>
+                {launcherCacheStats ? (
+                  <div className="muted">
+                    {launcherCacheStats.total_bytes > 0 ? formatFileSize(launcherCacheStats.total_bytes) : "Empty"} ·{" "}
+                    {launcherCacheStats.entries
+                      .map((entry) => `${entry.name} ${entry.bytes > 0 ? formatFileSize(entry.bytes) : "0 B"}`)
+                      .join(" · ")}
+                  </div>
+                ) : null}
+                {launcherCacheStats?.warning ? <div className="errorBox">{launcherCacheStats.warning}</div> : null}
+                <div className="row">
+                  <button className="btn" onClick={() => void refreshLauncherCacheStats()} disabled={launcherCacheBusy}>
+                    {launcherCacheBusy ? "Working…" : launcherCacheStats ? "Refresh size" : "Check size"}
+                  </button>
+                  <button
+                    className="btn"
+                    onClick={() => void openLauncherCache().catch((e: any) => setLauncherErr(e?.toString?.() ?? String(e)))}
+                  >
+                    Open folder
+                  </button>
+                  <button
+                    className="btn danger"
+                    onClick={() => void onClearLauncherCache()}
+                    disabled={launcherCacheBusy || (launcherCacheStats?.running_instances ?? 0) > 0}
+                  >
+                    Clear cache
+                  </button>
+                </div>
+              </div>
+
+              <div>
+                <div className="settingTitle">Diagnostics</div>
+                <div className="settingSub">
+                  Collects app paths, state file health, keyring, API reachability, Java runtimes and import sources for bug reports.
+                </div>
+                <div className="row">
+                  <button className="btn" onClick={() => void onCopyDiagnostics()} disabled={diagnosticsBusy}>
+                    {diagnosticsBusy ? "Collecting…" : "Copy diagnostics"}
+                  </button>
+                </div>
+              </div>
+
               <div>
                 <button className="btn" onClick={() => setShowAdvancedClientId((v) => !v)}>
                   {showAdvancedClientId ? "Hide advanced OAuth settings" : "Show advanced OAuth settings"}
@@ -7039,6 +7828,16 @@ export default function App() {
                   }}
                   options={UPDATE_APPLY_SCOPE_OPTIONS}
                 />
+                <MenuSelect
+                  value={safeUpdateMode}
+                  labelPrefix="Safe update"
+                  onChange={(v) => {
+                    const next = normalizeSafeUpdateMode(v);
+                    setSafeUpdateMode(next);
+                    void persistUpdateSchedulerPrefs({ safeUpdateMode: next });
+                  }}
+                  options={SAFE_UPDATE_MODE_OPTIONS}
+                />
                 {updatePrefsBusy ? <span className="chip">Saving…</span> : <span className="chip subtle">Saved</span>}
               </div>
               <div className="updatesScreenStatsRow">
@@ -8148,8 +8947,9 @@ export default function App() {
             <SegmentedControl
               value={discoverContentType}
               onChange={(v) => {
-                setDiscoverContentType((v as DiscoverContentType) ?? "mods");
-                setFilterLoaders([]);
+                const next = (v as DiscoverContentType) ?? "mods";
+                setDiscoverContentType(next);
+                applyDiscoverPreferences(next);
                 setOffset(0);
               }}
               options={DISCOVER_CONTENT_OPTIONS}
@@ -8202,6 +9002,19 @@ export default function App() {
               }}
             />
 
+            {discoverSource === "all" ? (
+              <MenuSelect
+                value={discoverPreferredProvider}
+                labelPrefix="Prefer"
+                options={DISCOVER_PREFER_OPTIONS}
+                align="end"
+                onChange={(v) => {
+                  setDiscoverPreferredProvider(v || "modrinth");
+                  setOffset(0);
+                }}
+              />
+            ) : null}
+
             <button className="btn primary" onClick={() => runSearch(0)} disabled={discoverBusy}>
               {discoverBusy ? "Searching…" : "Search"}
             </button>
@@ -8239,7 +9052,7 @@ export default function App() {
                 <MultiSelectDropdown
                   values={filterCategories}
                   placeholder="Categories: Any"
-                  groups={MOD_CATEGORY_GROUPS}
+                  groups={discoverCategoryGroups ?? MOD_CATEGORY_GROUPS}
                   onChange={(v) => {
                     setFilterCategories(v);
                     setOffset(0);
@@ -8340,26 +9153,44 @@ export default function App() {
                   >
                     Add to creator
                   </button>
-                  <button
-                    className="btn primary installAction"
-                    onClick={() =>
-                      openInstall({
-                        source: h.source === "curseforge" ? "curseforge" : "modrinth",
-                        projectId: h.project_id,
-                        title: h.title,
-                        contentType:
-                          (h.content_type as DiscoverContentType) === "modpacks"
-                            ? "modpacks"
-                            : ((h.content_type as DiscoverContentType) ?? discoverContentType),
-                        iconUrl: h.icon_url,
-                        description: h.description,
-                      })
-                    }
-                    title={h.content_type === "modpacks" ? "Modpacks are imported as templates" : "Install to instance"}
-                    disabled={h.content_type === "modpacks"}
-                  >
-                    <Icon name="download" /> {h.content_type === "modpacks" ? "Template only" : "Install"}
-                  </button>
+                  {h.content_type === "modpacks" && modpackInstallBusy === h.project_id ? (
+                    <>
+                      <span className="muted" title={installProgress?.project_id === h.project_id ? installProgress.message ?? "" : ""}>
+                        {installProgress?.project_id === h.project_id
+                          ? `${installProgress.message ?? "Installing…"} ${formatPercent(installProgress.percent)}`.trim()
+                          : "Installing…"}
+                      </span>
+                      <button className="btn" onClick={() => onCancelModpackInstall(h.project_id)}>
+                        Cancel
+                      </button>
+                    </>
+                  ) : h.content_type === "modpacks" ? (
+                    <button
+                      className="btn primary installAction"
+                      onClick={() => onInstallModpack({ source: h.source === "curseforge" ? "curseforge" : "modrinth", projectId: h.project_id, title: h.title })}
+                      title="Install this modpack into a new instance"
+                      disabled={modpackInstallBusy !== null}
+                    >
+                      <Icon name="download" /> Install pack
+                    </button>
+                  ) : (
+                    <button
+                      className="btn primary installAction"
+                      onClick={() =>
+                        openInstall({
+                          source: h.source === "curseforge" ? "curseforge" : "modrinth",
+                          projectId: h.project_id,
+                          title: h.title,
+                          contentType: (h.content_type as DiscoverContentType) ?? discoverContentType,
+                          iconUrl: h.icon_url,
+                          description: h.description,
+                        })
+                      }
+                      title="Install to instance"
+                    >
+                      <Icon name="download" /> Install
+                    </button>
+                  )}
                 </div>
               </div>
             ))}
@@ -8385,7 +9216,7 @@ export default function App() {
             <button
               className="btn"
               onClick={() => runSearch(Math.min((pages - 1) * limit, offset + limit))}
-              disabled={discoverBusy || offset + limit >= totalHits}
+              disabled={discoverBusy || discoverEndReached || offset + limit >= totalHits}
             >
               Next →
             </button>
@@ -8456,7 +9287,7 @@ export default function App() {
       const showOpenLaunchLogAction =
         hasNativeRunningForInstance || String(launchFailure?.method ?? "").toLowerCase() === "native";
       const launchStage = launchStageByInstance[inst.id] ?? null;
-      const launchStageLabel = launchStage?.label?.trim() || launchStageBadgeLabel(
+      const launchStageLabel = launchStageProgressLabel(launchStage).trim() || launchStageBadgeLabel(
         launchStage?.status,
         launchStage?.message
       );
@@ -8628,7 +9459,10 @@ export default function App() {
                       <span className="chip subtle">{hasRunningForInstance ? "Running" : "Never played"}</span>
                       {hasLaunchFailure ? <span className="chip">Last launch failed</span> : null}
                       {launchStageLabel ? (
-                        <span className="chip">{launchStage?.status === "starting" ? `Launching: ${launchStageLabel}` : launchStageLabel}</span>
+                        <span className="chip" title={nativeDownloadByInstance[inst.id]?.current_file ?? undefined}>
+                          {launchStage?.status === "starting" ? `Launching: ${launchStageLabel}` : launchStageLabel}
+                          {nativeDownloadByInstance[inst.id] ? ` · ${nativeDownloadDetail(nativeDownloadByInstance[inst.id])}` : ""}
+                        </span>
                       ) : null}
                     </div>
                   </div>
@@ -8839,13 +9673,24 @@ export default function App() {
                           </div>
                         </div>
                         <div className="instanceContentUpdateRow">
-                          <button
-                            className="btn"
-                            onClick={() => onCheckUpdates(inst)}
-                            disabled={updateBusy || updateAllBusy || instanceContentType !== "mods"}
-                          >
-                            {updateBusy ? "Checking…" : "Refresh"}
-                          </button>
+                          {instanceContentType === "datapacks" ? (
+                            <button
+                              className="btn"
+                              onClick={() => onSyncWorldDatapacks(inst)}
+                              disabled={updateBusy || updateAllBusy}
+                              title="Pick up datapacks added or removed in world folders"
+                            >
+                              {updateBusy ? "Scanning…" : "Rescan worlds"}
+                            </button>
+                          ) : (
+                            <button
+                              className="btn"
+                              onClick={() => onCheckUpdates(inst)}
+                              disabled={updateBusy || updateAllBusy || instanceContentType !== "mods"}
+                            >
+                              {updateBusy ? "Checking…" : "Refresh"}
+                            </button>
+                          )}
                           <button
                             className="btn primary"
                             onClick={() => onUpdateAll(inst)}
@@ -8853,7 +9698,48 @@ export default function App() {
                           >
                             {updateAllBusy ? "Updating…" : `Update all${updateCheck?.update_count ? ` (${updateCheck.update_count})` : ""}`}
                           </button>
+                          <button
+                            className="btn"
+                            onClick={() => onIdentifyLocalContent(inst)}
+                            disabled={updateBusy || updateAllBusy}
+                            title="Match imported files against Modrinth and CurseForge so they get update checks"
+                          >
+                            Identify local files
+                          </button>
+                          {instanceContentType === "mods" ? (
+                            <button
+                              className="btn"
+                              onClick={() => onCheckMissingDependencies(inst)}
+                              disabled={updateBusy || updateAllBusy}
+                              title="Find enabled mods whose required dependencies are missing or disabled"
+                            >
+                              Check dependencies
+                            </button>
+                          ) : null}
                         </div>
+                        {missingDeps && missingDeps.instance_id === inst.id && missingDeps.missing.length > 0 ? (
+                          <div className="muted instanceContentControlMeta">
+                            {missingDeps.missing.map((dep) => (
+                              <div key={`${dep.dependent_version_id}:${dep.project_id}`}>
+                                {dep.dependent_name} needs {dep.name}
+                                {dep.status === "disabled"
+                                  ? " (disabled)"
+                                  : dep.status === "unverified"
+                                    ? " (not found on Modrinth; a CurseForge or local file may already provide it)"
+                                    : " (not installed)"}
+                              </div>
+                            ))}
+                            {missingDeps.missing.some((dep) => dep.status !== "unverified") ? (
+                              <button
+                                className="btn primary"
+                                onClick={() => onInstallMissingDependencies(inst)}
+                                disabled={updateBusy || updateAllBusy}
+                              >
+                                {updateBusy ? "Working…" : "Install missing dependencies"}
+                              </button>
+                            ) : null}
+                          </div>
+                        ) : null}
                         <div className="instanceSnapshotRow">
                           {snapshots.length > 0 ? (
                             <MenuSelect
@@ -8909,6 +9795,12 @@ export default function App() {
                                 <div className="updatesListMeta">
                                   {u.current_version_number} → {u.latest_version_number}
                                 </div>
+                                {u.changelog ? (
+                                  <details className="updatesChangelog">
+                                    <summary>Changelog</summary>
+                                    <div className="updatesChangelogBody">{u.changelog}</div>
+                                  </details>
+                                ) : null}
                               </div>
                             ))}
                             {updateCheck.updates.length > 8 ? (
@@ -8979,11 +9871,19 @@ export default function App() {
                                       Missing file
                                     </span>
                                   ) : null}
+                                  {m.loader_fallback ? (
+                                    <span className="chip" title={`No ${inst.loader} build was available, so the ${m.loader_fallback} build was installed.`}>
+                                      {m.loader_fallback === "fabric" ? "Fabric build" : `${m.loader_fallback} build`}
+                                    </span>
+                                  ) : null}
                                 </div>
                                 <div className="instanceModsNameMeta">
                                   Source: {m.source}
                                   {m.content_type ? ` · ${m.content_type}` : ""}
                                   {m.target_worlds?.length ? ` · worlds: ${m.target_worlds.join(", ")}` : ""}
+                                  {m.enabled_state === "partial" && m.disabled_worlds?.length
+                                    ? ` · disabled in: ${m.disabled_worlds.join(", ")}`
+                                    : ""}
                                 </div>
                               </div>
                             </div>
@@ -8994,7 +9894,17 @@ export default function App() {
                             </div>
 
                             <div className="instanceModsActionCell">
-                              {(m.content_type ?? "mods") === "mods" ? (
+                              {m.enabled_state === "partial" ? (
+                                <button
+                                  className="btn primary instanceEnableBtn"
+                                  onClick={() => onToggleInstalledMod(inst, m, true)}
+                                  disabled={toggleBusyVersion === m.version_id || toggleBusyVersion === "__bulk__" || !m.file_exists}
+                                  aria-label="Enable datapack in all worlds"
+                                  title={`Disabled in ${m.disabled_worlds?.join(", ") ?? "some worlds"}`}
+                                >
+                                  {toggleBusyVersion === m.version_id ? "Applying…" : "Enable all"}
+                                </button>
+                              ) : (m.content_type ?? "mods") === "mods" || m.content_type === "datapacks" ? (
                                 <button
                                   className={`btn ${m.enabled ? "danger" : "primary"} instanceEnableBtn`}
                                   onClick={() => onToggleInstalledMod(inst, m, !m.enabled)}
@@ -9094,6 +10004,14 @@ export default function App() {
                             const hasBackup = Boolean(world.latest_backup_at) && (world.backup_count ?? 0) > 0;
                             const rollbackBusyForWorld = Boolean(worldRollbackBusyById[world.id]);
                             const rollbackDisabled = !hasBackup || rollbackBusyForWorld;
+                            const levelDetails = [
+                              world.version,
+                              world.game_mode ? world.game_mode[0].toUpperCase() + world.game_mode.slice(1) : null,
+                              world.difficulty ? world.difficulty[0].toUpperCase() + world.difficulty.slice(1) : null,
+                              world.last_played
+                                ? `Last played ${new Date(world.last_played).toLocaleString()}`
+                                : null,
+                            ].filter(Boolean);
                             return (
                               <div key={world.id} className="card instanceWorldCard">
                                 <div className="instanceWorldCardTop">
@@ -9105,6 +10023,11 @@ export default function App() {
                                   </span>
                                 </div>
                                 <div className="muted">{world.path}</div>
+                                {levelDetails.length > 0 ? (
+                                  <div className="instanceWorldBackupMeta" title={world.seed ? `Seed: ${world.seed}` : undefined}>
+                                    {levelDetails.join(" · ")}
+                                  </div>
+                                ) : null}
                                 <div className="instanceWorldBackupMeta">
                                   {hasBackup
                                     ? `Latest backup: ${formatDateTime(world.latest_backup_at)}`
@@ -9686,6 +10609,14 @@ export default function App() {
                     <Icon name="download" size={16} />
                     Export mods zip
                   </button>
+                  <button
+                    className="btn"
+                    onClick={() => onExportModsZip(inst, true)}
+                    title="Leaves out mods Modrinth marks as client-only"
+                  >
+                    <Icon name="download" size={16} />
+                    Export server mods
+                  </button>
                 </div>
               </div>
             </aside>
@@ -9803,9 +10734,12 @@ export default function App() {
 
                         <div className="settingCard">
                           <div className="settingTitle">Notes</div>
-                          <div className="settingSub">Personal reminder for this instance.</div>
+                          <div className="settingSub">
+                            Personal reminder for this instance, like a server address. Library search matches notes too.
+                          </div>
                           <textarea
                             className="textarea"
+                            maxLength={4000}
                             value={instanceNotesDraft}
                             onChange={(e) => setInstanceNotesDraft(e.target.value)}
                             onBlur={() => void onCommitInstanceNotes(inst)}
@@ -9813,6 +10747,32 @@ export default function App() {
                             disabled={instanceSettingsBusy}
                           />
                         </div>
+
+                        <div className="settingCard">
+                          <div className="settingTitle">Minecraft account</div>
+                          <div className="settingSub">
+                            Launch this instance as a specific account instead of the launcher-wide selection.
+                          </div>
+                          <MenuSelect
+                            value={instSettings.account_id_override ?? ""}
+                            labelPrefix="Account"
+                            onChange={(v) =>
+                              void persistInstanceChanges(
+                                inst,
+                                { settings: { account_id_override: v || null } },
+                                v ? "Instance account saved." : "Instance uses the launcher account again."
+                              )
+                            }
+                            options={[
+                              { value: "", label: "Launcher default" },
+                              ...launcherAccounts.map((acct) => ({ value: acct.id, label: acct.username })),
+                              ...(instSettings.account_id_override &&
+                              !launcherAccounts.some((acct) => acct.id === instSettings.account_id_override)
+                                ? [{ value: instSettings.account_id_override, label: "Missing account" }]
+                                : []),
+                            ]}
+                          />
+                        </div>
                       </div>
                     </>
                   )}
@@ -9843,6 +10803,35 @@ export default function App() {
                             ]}
                             variant="scroll"
                           />
+                          {inst.loader === "fabric" || inst.loader === "forge" ? (
+                            <MenuSelect
+                              value={inst.loader_version ?? ""}
+                              labelPrefix="Build"
+                              onChange={(v) => {
+                                const next = v || null;
+                                if (next === (inst.loader_version ?? null)) return;
+                                void onSetLoaderVersion(inst, next);
+                              }}
+                              options={[
+                                { value: "", label: "Latest" },
+                                ...(inst.loader_version && !loaderVersionOptions.includes(inst.loader_version)
+                                  ? [{ value: inst.loader_version, label: inst.loader_version }]
+                                  : []),
+                                ...loaderVersionOptions.slice(0, 50).map((v) => ({ value: v, label: v })),
+                              ]}
+                              placement="top"
+                            />
+                          ) : null}
+                          {(inst.loader === "fabric" || inst.loader === "forge") && !inst.loader_version ? (
+                            <button
+                              className="btn"
+                              onClick={() => onRefreshLoaderVersion(inst)}
+                              disabled={loaderRefreshBusy}
+                              title="Loader versions are cached for a day; this checks for a newer one now"
+                            >
+                              {loaderRefreshBusy ? "Checking…" : "Check for newer loader"}
+                            </button>
+                          ) : null}
                         </div>
 
                         <div className="settingCard settingCardVersion">
@@ -9860,6 +10849,20 @@ export default function App() {
                           <div className="settingSub">Shown in Discover filters and install prompts.</div>
                         </div>
 
+                        <div className="settingCard">
+                          <div className="settingTitle">Repair game files</div>
+                          <div className="settingSub">
+                            Re-checks the Minecraft jar, libraries and assets and downloads anything missing or corrupt.
+                          </div>
+                          <button
+                            className="btn"
+                            onClick={() => onRepairInstanceRuntime(inst)}
+                            disabled={repairRuntimeBusy}
+                          >
+                            {repairRuntimeBusy ? "Repairing…" : "Repair"}
+                          </button>
+                        </div>
+
                         <div className="settingCard">
                           <div className="settingTitle">Instance location</div>
                           <div className="settingSub">Where files are stored on disk.</div>
@@ -9913,6 +10916,24 @@ export default function App() {
                             <span className="togglePill" />
                             <span>Prefer release builds</span>
                           </label>
+                          {inst.loader === "quilt" ? (
+                            <label className="toggleRow">
+                              <input
+                                type="checkbox"
+                                checked={instSettings.allow_fabric_on_quilt}
+                                onChange={(e) =>
+                                  void persistInstanceChanges(
+                                    inst,
+                                    { settings: { allow_fabric_on_quilt: e.target.checked } },
+                                    "Update preference saved."
+                                  )
+                                }
+                                disabled={instanceSettingsBusy}
+                              />
+                              <span className="togglePill" />
+                              <span>Use Fabric builds when no Quilt build exists</span>
+                            </label>
+                          ) : null}
                         </div>
                       </div>
                     </>
@@ -9986,6 +11007,48 @@ export default function App() {
                           ) : null}
                         </div>
 
+                        <div className="settingCard">
+                          <div className="settingTitle">New instance defaults</div>
+                          <div className="settingSub">
+                            Use this instance's memory, JVM arguments, graphics and backup settings for every new instance. Notes and account overrides are not copied.
+                          </div>
+                          <div className="row">
+                            <button
+                              className="btn"
+                              onClick={() => void onSaveInstanceSettingsAsDefault(inst)}
+                              disabled={busy === "instance-defaults"}
+                            >
+                              {busy === "instance-defaults" ? "Saving…" : "Save as default"}
+                            </button>
+                          </div>
+                        </div>
+
+                        <div className="settingCard">
+                          <div className="settingTitle">Launch check</div>
+                          <div className="settingSub">
+                            Runs the native pre-launch steps (Java, account, loader, runtime links) without starting the game.
+                          </div>
+                          <div className="row">
+                            <button className="btn" onClick={() => void onDryRunLaunch(inst)} disabled={dryRunBusy}>
+                              {dryRunBusy ? "Checking…" : "Run launch check"}
+                            </button>
+                          </div>
+                          {dryRunReport && dryRunReport.instance_id === inst.id ? (
+                            <div className="settingListMini">
+                              {dryRunReport.steps.map((step) => (
+                                <div key={step.step} className="settingListMiniRow">
+                                  <div style={{ minWidth: 0 }}>
+                                    <div style={{ fontWeight: 900 }}>
+                                      {step.ok ? "✓" : "✕"} {humanizeToken(step.step)}
+                                    </div>
+                                    <div className="muted" style={{ wordBreak: "break-word" }}>{step.message}</div>
+                                  </div>
+                                </div>
+                              ))}
+                            </div>
+                          ) : null}
+                        </div>
+
                         <div className="settingCard">
                           <div className="settingTitle">Memory</div>
                           <div className="settingSub">Set Java heap size in MB for this instance.</div>
@@ -10123,7 +11186,10 @@ export default function App() {
 
                         <div className="settingCard">
                           <div className="settingTitle">Optional display features</div>
-                          <div className="settingSub">Toggles that can improve image quality at runtime.</div>
+                          <div className="settingSub">
+                            Toggles that can improve image quality at runtime. Shaders need Iris, Oculus or OptiFine and an
+                            installed shader pack.
+                          </div>
                           <label className="toggleRow">
                             <input
                               type="checkbox"
@@ -10131,7 +11197,7 @@ export default function App() {
                               onChange={(e) =>
                                 void persistInstanceChanges(
                                   inst,
-                                  { settings: { enable_shaders: e.target.checked } },
+                                  { settings: { enable_shaders: e.target.checked, shaders_managed: true } },
                                   "Graphics preference saved."
                                 )
                               }
@@ -10459,7 +11525,10 @@ export default function App() {
     const visibleInstances =
       libraryScope === "downloaded"
         ? []
-        : instances.filter((x) => x.name.toLowerCase().includes(libraryQuery.toLowerCase()));
+        : instances.filter((x) => {
+            const query = libraryQuery.toLowerCase();
+            return x.name.toLowerCase().includes(query) || (x.settings?.notes ?? "").toLowerCase().includes(query);
+          });
 
     const filtered = [...visibleInstances].sort((a, b) => {
       if (librarySort === "name") {
@@ -10617,7 +11686,7 @@ export default function App() {
                         const isRunning = runningIds.has(inst.id);
                         const runningLaunch = runningInstances.find((run) => run.instance_id === inst.id) ?? null;
                         const launchStage = launchStageByInstance[inst.id] ?? null;
-                        const launchStageLabel = launchStage?.label?.trim() || launchStageBadgeLabel(
+                        const launchStageLabel = launchStageProgressLabel(launchStage).trim() || launchStageBadgeLabel(
                           launchStage?.status,
                           launchStage?.message
                         );
@@ -10652,7 +11721,10 @@ export default function App() {
                               </div>
                               {isRunning ? <span className="chip">Running</span> : null}
                               {!isRunning && launchStageLabel ? (
-                                <span className="chip">{launchStage?.status === "starting" ? `Launching: ${launchStageLabel}` : launchStageLabel}</span>
+                                <span className="chip" title={nativeDownloadByInstance[inst.id]?.current_file ?? undefined}>
+                          {launchStage?.status === "starting" ? `Launching: ${launchStageLabel}` : launchStageLabel}
+                          {nativeDownloadByInstance[inst.id] ? ` · ${nativeDownloadDetail(nativeDownloadByInstance[inst.id])}` : ""}
+                        </span>
                               ) : null}
                             </div>
 
@@ -10840,6 +11912,28 @@ export default function App() {
 
       <main className="content">
         {error ? <div className="errorBox" style={{ marginTop: 0, marginBottom: 12 }}>{error}</div> : null}
+        {crashAfterUpdatePrompt ? (
+          <div className="errorBox crashAfterUpdateBox" style={{ marginTop: 0, marginBottom: 12 }}>
+            <div>{crashAfterUpdatePrompt.message}</div>
+            <div className="row" style={{ marginTop: 8, gap: 8 }}>
+              <button
+                className="btn primary"
+                disabled={rollbackBusy}
+                onClick={() => {
+                  const prompt = crashAfterUpdatePrompt;
+                  const inst = instances.find((item) => item.id === prompt.instanceId);
+                  setCrashAfterUpdatePrompt(null);
+                  if (inst) void onRollbackToSnapshot(inst, prompt.snapshotId);
+                }}
+              >
+                Roll back
+              </button>
+              <button className="btn" onClick={() => setCrashAfterUpdatePrompt(null)}>
+                Keep updates
+              </button>
+            </div>
+          </div>
+        ) : null}
         {renderContent()}
       </main>
 
@@ -11188,6 +12282,7 @@ export default function App() {
                   const preview = installPlanPreview[inst.id];
                   const previewBusy = installPlanPreviewBusy[inst.id];
                   const previewErr = installPlanPreviewErr[inst.id];
+                  const sizeEstimate = installSizeEstimate[inst.id];
                   return (
                     <div key={inst.id} className="installRow">
                       <div className="installRowLeft">
@@ -11213,6 +12308,24 @@ export default function App() {
                                   ? `Will install: ${preview.will_install_mods} mod${preview.will_install_mods === 1 ? "" : "s"}${preview.dependency_mods > 0 ? ` (${preview.dependency_mods} required dependenc${preview.dependency_mods === 1 ? "y" : "ies"})` : ""}`
                                   : "Checking required dependencies…"}
                           </div>
+                          <div className={`installRowPreview ${typeof sizeEstimate === "string" ? "error" : ""}`}>
+                            {sizeEstimate === undefined ? (
+                              <button
+                                className="btn"
+                                onClick={() => onEstimateInstallSize(inst)}
+                                disabled={installSizeBusy !== null}
+                                title="Sum the provider-reported file sizes without downloading anything"
+                              >
+                                {installSizeBusy === inst.id ? "Checking size…" : "Check download size"}
+                              </button>
+                            ) : typeof sizeEstimate === "string" ? (
+                              `Size unavailable: ${sizeEstimate}`
+                            ) : sizeEstimate.total_bytes > 0 ? (
+                              `Download size: ${formatFileSize(sizeEstimate.total_bytes)} across ${sizeEstimate.files.filter((f) => !f.already_installed).length} file${sizeEstimate.files.filter((f) => !f.already_installed).length === 1 ? "" : "s"}`
+                            ) : (
+                              "Nothing new to download."
+                            )}
+                          </div>
                         </div>
                       </div>
 
@@ -11385,6 +12498,27 @@ export default function App() {
                           </pre>
                         </div>
 
+                        {projectOpen.gallery?.length ? (
+                          <div className="card projectSectionCard projectSectionGallery">
+                            <div className="projectSectionTitle">Gallery</div>
+                            <div className="projectGallery">
+                              {projectOpen.gallery.map((img) => (
+                                <a
+                                  key={img.url}
+                                  className={`projectGalleryItem${img.featured ? " featured" : ""}`}
+                                  href={img.url}
+                                  target="_blank"
+                                  rel="noreferrer"
+                                  title={img.description || img.title || undefined}
+                                >
+                                  <img src={img.url} alt={img.title ?? ""} loading="lazy" />
+                                  {img.title ? <div className="projectGalleryCaption">{img.title}</div> : null}
+                                </a>
+                              ))}
+                            </div>
+                          </div>
+                        ) : null}
+
                         <div className="card projectSectionCard projectSectionLinks">
                           <div className="projectSectionTitle">Links</div>
                           <div className="projectLinks">
@@ -11842,6 +12976,11 @@ export default function App() {
             </div>
             <div className="muted">
               Waiting for Microsoft confirmation…
+              {msLoginState?.seconds_remaining != null
+                ? ` Code expires in ${Math.floor(msLoginState.seconds_remaining / 60)}:${String(
+                    msLoginState.seconds_remaining % 60
+                  ).padStart(2, "0")}.`
+                : ""}
             </div>
           </div>
         </div>
@@ -38,6 +38,14 @@ export type Project = {
   issues_url?: string | null;
   source_url?: string | null;
   discord_url?: string | null;
+  gallery?: ProjectGalleryImage[];
+};
+
+export type ProjectGalleryImage = {
+  url: string;
+  title?: string | null;
+  description?: string | null;
+  featured: boolean;
 };
 
 export type ProjectVersion = {
@@ -121,13 +129,35 @@ export async function searchMods(input: {
 export async function getProject(projectId: string): Promise<Project> {
   const res = await fetch(`${API}/project/${projectId}`, { headers: headers() });
   if (!res.ok) throw new Error(`Modrinth project failed (${res.status})`);
-  return (await res.json()) as Project;
+  const raw = (await res.json()) as Project & {
+    gallery?: (ProjectGalleryImage & { ordering?: number })[];
+  };
+  // Modrinth returns gallery items unordered; `ordering` is the author's order.
+  const gallery = [...(raw.gallery ?? [])]
+    .sort((a, b) => (a.ordering ?? 0) - (b.ordering ?? 0))
+    .map((img) => ({
+      url: img.url,
+      title: img.title ?? null,
+      description: img.description ?? null,
+      featured: Boolean(img.featured),
+    }));
+  return { ...raw, gallery };
 }
 
-export async function getProjectVersions(projectId: string): Promise<ProjectVersion[]> {
-  const res = await fetch(`${API}/project/${projectId}/version`, { headers: headers() });
+export async function getProjectVersions(
+  projectId: string,
+  filters?: { gameVersion?: string; loader?: string; limit?: number }
+): Promise<ProjectVersion[]> {
+  const params = new URLSearchParams();
+  if (filters?.gameVersion) params.set("game_versions", JSON.stringify([filters.gameVersion]));
+  if (filters?.loader) params.set("loaders", JSON.stringify([filters.loader]));
+  const query = params.toString();
+  const res = await fetch(`${API}/project/${projectId}/version${query ? `?${query}` : ""}`, {
+    headers: headers(),
+  });
   if (!res.ok) throw new Error(`Modrinth versions failed (${res.status})`);
-  return (await res.json()) as ProjectVersion[];
+  const versions = (await res.json()) as ProjectVersion[];
+  return filters?.limit && filters.limit > 0 ? versions.slice(0, filters.limit) : versions;
 }
 
 export async function getProjectMembers(projectId: string): Promise<ProjectMember[]> {
@@ -1,48 +1,132 @@
-import { invoke } from "@tauri-apps/api/tauri";
+import { invoke as tauriInvoke } from "@tauri-apps/api/tauri";
 import type {
   AccountDiagnostics,
+  AppErrorCode,
+  AppErrorPayload,
   BeginMicrosoftLoginResult,
+  ClearLauncherCacheResult,
   CreateInstanceFromModpackFileResult,
+  InstallModpackResult,
   CreatorPreset,
   CurseforgeApiStatus,
   CurseforgeProjectDetail,
+  DiagnosticsReport,
+  MoveInstancesRootResult,
+  MigrateLegacyCredentialsResult,
+  ModpackFileInfo,
+  DiscoverCategory,
   DiscoverContentType,
   DiscoverSearchResult,
   DiscoverSource,
   ExportModsResult,
+  ImportInstanceBundleResult,
+  FindOrphanedContentResult,
   ImportInstanceFromLauncherResult,
   InstanceSettings,
   InstanceWorld,
   InstallPlanPreview,
+  InstallSizeEstimate,
+  InstallDiscoverContentBatchResult,
+  InstanceDiffResult,
   Instance,
   JavaRuntimeCandidate,
+  DryRunLaunchResult,
   LauncherImportSource,
   InstalledMod,
   LauncherAccount,
+  LauncherCacheStats,
   LauncherSettings,
   LaunchResult,
   LaunchMethod,
   Loader,
   MicrosoftLoginState,
   ModUpdateCheckResult,
+  VersionMigrationReport,
+  VersionChangelog,
+  MigrateInstanceVersionResult,
   OpenInstancePathResult,
+  OpenInstanceSubpathResult,
+  RemoveOrphanedContentResult,
+  RepairInstanceContentResult,
   RevealConfigEditorFileResult,
   PresetApplyPreview,
   PresetApplyResult,
   PresetsJsonIoResult,
   RollbackResult,
+  RestoreSnapshotFileResult,
   ReadInstanceLogsResult,
+  CrashReportEntry,
+  RotatedLogEntry,
+  InstanceLogSourceApi,
   RunningInstance,
+  SetActiveShaderResult,
+  SetInstanceIconResult,
+  ImportLocalModFileResult,
+  ReadLocalImageDataUrlResult,
   SnapshotMeta,
+  CheckUpdatesAllInstancesResult,
+  ExportWorldResult,
+  SyncWorldDatapacksResult,
+  PruneMissingDatapackTargetsResult,
+  CheckMissingDependenciesResult,
+  InstallMissingDependenciesResult,
+  MissingDependency,
+  DiscoverPreferences,
+  IdentifyLocalContentResult,
+  SuggestExportFilenameResult,
+  RepairInstanceRuntimeResult,
   UpdateAllResult,
   WorldConfigFileEntry,
   ReadWorldConfigFileResult,
   WriteWorldConfigFileResult,
   WorldRollbackResult,
+  BackupVerificationResult,
+  CleanupWorldBackupsResult,
 } from "./types";
 
-export function listInstances(): Promise<Instance[]> {
-  return invoke("list_instances");
+/**
+ * Structured command error. `toString()` returns the plain message so existing
+ * `e?.toString?.()` handlers keep working; branch on `code` where it matters.
+ */
+export class AppError extends Error {
+  code: AppErrorCode;
+  hint: string | null;
+
+  constructor(payload: AppErrorPayload) {
+    super(payload.message);
+    this.name = "AppError";
+    this.code = payload.code;
+    this.hint = payload.hint ?? null;
+  }
+
+  toString() {
+    return this.message;
+  }
+}
+
+function isAppErrorPayload(value: unknown): value is AppErrorPayload {
+  return (
+    typeof value === "object" &&
+    value !== null &&
+    typeof (value as AppErrorPayload).code === "string" &&
+    typeof (value as AppErrorPayload).message === "string"
+  );
+}
+
+async function invoke<T>(cmd: string, args?: Record<string, unknown>): Promise<T> {
+  try {
+    return await tauriInvoke<T>(cmd, args);
+  } catch (e) {
+    throw isAppErrorPayload(e) ? new AppError(e) : e;
+  }
+}
+
+export function listInstances(input?: {
+  tags?: string[];
+  matchAll?: boolean;
+  notesContains?: string;
+}): Promise<Instance[]> {
+  return invoke("list_instances", input ? { args: input } : undefined);
 }
 
 export function createInstance(input: {
@@ -54,14 +138,43 @@ export function createInstance(input: {
   return invoke("create_instance", { args: input });
 }
 
+export function inspectModpackFile(filePath: string): Promise<ModpackFileInfo> {
+  return invoke("inspect_modpack_file", { args: { filePath } });
+}
+
 export function createInstanceFromModpackFile(input: {
   filePath: string;
   name?: string;
   iconPath?: string | null;
+  mcVersion?: string;
+  loader?: Loader;
 }): Promise<CreateInstanceFromModpackFileResult> {
   return invoke("create_instance_from_modpack_file", { args: input });
 }
 
+export function installModrinthModpack(input: {
+  projectId: string;
+  versionId?: string | null;
+  name?: string;
+  iconPath?: string | null;
+}): Promise<InstallModpackResult> {
+  return invoke("install_modrinth_modpack", { args: input });
+}
+
+export function installCurseforgeModpack(input: {
+  projectId: string;
+  fileId?: number | null;
+  name?: string;
+  iconPath?: string | null;
+}): Promise<InstallModpackResult> {
+  return invoke("install_curseforge_modpack", { args: input });
+}
+
+/** Stops the running install of this pack before its next file and removes the new instance. */
+export function cancelModpackInstall(input: { projectId: string }): Promise<void> {
+  return invoke("cancel_modpack_install", { args: input });
+}
+
 export function listLauncherImportSources(): Promise<LauncherImportSource[]> {
   return invoke("list_launcher_import_sources");
 }
@@ -84,16 +197,36 @@ export function updateInstance(input: {
   return invoke("update_instance", { args: input });
 }
 
+export function toggleInstanceFavorite(input: {
+  instanceId: string;
+}): Promise<Instance> {
+  return invoke("toggle_instance_favorite", { args: input });
+}
+
+export function reorderInstances(input: {
+  instanceIds: string[];
+}): Promise<Instance[]> {
+  return invoke("reorder_instances", { args: input });
+}
+
+export function updateInstanceTags(input: {
+  instanceId: string;
+  tags: string[];
+}): Promise<Instance> {
+  return invoke("update_instance_tags", { args: input });
+}
+
 export function setInstanceIcon(input: {
   instanceId: string;
   iconPath?: string | null;
-}): Promise<Instance> {
+}): Promise<SetInstanceIconResult> {
   return invoke("set_instance_icon", { args: input });
 }
 
 export function readLocalImageDataUrl(input: {
   path: string;
-}): Promise<string> {
+  maxDimension?: number;
+}): Promise<ReadLocalImageDataUrlResult> {
   return invoke("read_local_image_data_url", { args: input });
 }
 
@@ -101,6 +234,26 @@ export function detectJavaRuntimes(): Promise<JavaRuntimeCandidate[]> {
   return invoke("detect_java_runtimes");
 }
 
+export function runDiagnostics(): Promise<DiagnosticsReport> {
+  return invoke("run_diagnostics");
+}
+
+export function moveInstancesRoot(input: { targetPath?: string }): Promise<MoveInstancesRootResult> {
+  return invoke("move_instances_root", { args: input });
+}
+
+export function getLauncherCacheStats(): Promise<LauncherCacheStats> {
+  return invoke("get_launcher_cache_stats");
+}
+
+export function openLauncherCache(): Promise<string> {
+  return invoke("open_launcher_cache");
+}
+
+export function clearLauncherCache(): Promise<ClearLauncherCacheResult> {
+  return invoke("clear_launcher_cache");
+}
+
 export function deleteInstance(id: string): Promise<void> {
   return invoke("delete_instance", { args: { id } });
 }
@@ -125,6 +278,16 @@ export function listInstalledMods(instanceId: string): Promise<InstalledMod[]> {
   return invoke("list_installed_mods", { args: { instanceId } });
 }
 
+export function searchInstalledContent(input: {
+  instanceId: string;
+  query: string;
+  contentType?: string | null;
+  enabled?: boolean | null;
+  source?: string | null;
+}): Promise<InstalledMod[]> {
+  return invoke("search_installed_content", { args: input });
+}
+
 export function setInstalledModEnabled(input: {
   instanceId: string;
   versionId: string;
@@ -133,13 +296,80 @@ export function setInstalledModEnabled(input: {
   return invoke("set_installed_mod_enabled", { args: input });
 }
 
+export function pinInstalledContent(input: {
+  instanceId: string;
+  versionId: string;
+  pinnedVersion?: string | null;
+}): Promise<InstalledMod> {
+  return invoke("pin_installed_content", { args: input });
+}
+
+export function repairInstanceContent(input: {
+  instanceId: string;
+}): Promise<RepairInstanceContentResult> {
+  return invoke("repair_instance_content", { args: input });
+}
+
+export function findOrphanedContent(input: {
+  instanceId: string;
+}): Promise<FindOrphanedContentResult> {
+  return invoke("find_orphaned_content", { args: input });
+}
+
+export function removeOrphanedContent(input: {
+  instanceId: string;
+  relativePaths: string[];
+}): Promise<RemoveOrphanedContentResult> {
+  return invoke("remove_orphaned_content", { args: input });
+}
+
+export function unpinInstalledContent(input: {
+  instanceId: string;
+  versionId: string;
+}): Promise<InstalledMod> {
+  return invoke("unpin_installed_content", { args: input });
+}
+
 export function importLocalModFile(input: {
   instanceId: string;
   filePath: string;
-}): Promise<InstalledMod> {
+  contentType?: DiscoverContentType;
+  targetWorlds?: string[];
+}): Promise<ImportLocalModFileResult> {
   return invoke("import_local_mod_file", { args: input });
 }
 
+export function syncWorldDatapacks(input: {
+  instanceId: string;
+}): Promise<SyncWorldDatapacksResult> {
+  return invoke("sync_world_datapacks", { args: input });
+}
+
+export function pruneMissingDatapackTargets(input: {
+  instanceId: string;
+}): Promise<PruneMissingDatapackTargetsResult> {
+  return invoke("prune_missing_datapack_targets", { args: input });
+}
+
+export function checkMissingDependencies(input: {
+  instanceId: string;
+}): Promise<CheckMissingDependenciesResult> {
+  return invoke("check_missing_dependencies", { args: input });
+}
+
+export function installMissingDependencies(input: {
+  instanceId: string;
+  dependencies: Pick<MissingDependency, "project_id" | "name">[];
+}): Promise<InstallMissingDependenciesResult> {
+  return invoke("install_missing_dependencies", { args: input });
+}
+
+export function identifyLocalContent(input: {
+  instanceId: string;
+}): Promise<IdentifyLocalContentResult> {
+  return invoke("identify_local_content", { args: input });
+}
+
 export function previewModrinthInstall(input: {
   instanceId: string;
   projectId: string;
@@ -148,12 +378,57 @@ export function previewModrinthInstall(input: {
   return invoke("preview_modrinth_install", { args: input });
 }
 
+/** Resolves the install plan with dependencies and sums provider-reported file sizes without downloading. */
+export function estimateInstallSize(input: {
+  instanceId: string;
+  source: "modrinth" | "curseforge";
+  projectId: string;
+  contentType?: string;
+}): Promise<InstallSizeEstimate> {
+  return invoke("estimate_install_size", { args: input });
+}
+
 export function checkModrinthUpdates(input: {
   instanceId: string;
 }): Promise<ModUpdateCheckResult> {
   return invoke("check_modrinth_updates", { args: input });
 }
 
+export function checkUpdatesAllInstances(input: {
+  instanceIds?: string[];
+  concurrency?: number;
+} = {}): Promise<CheckUpdatesAllInstancesResult> {
+  return invoke("check_updates_all_instances", { args: input });
+}
+
+export function cancelUpdateScan(): Promise<void> {
+  return invoke("cancel_update_scan");
+}
+
+export function getVersionChangelog(input: {
+  source?: "modrinth" | "curseforge";
+  projectId?: string;
+  versionId: string;
+}): Promise<VersionChangelog> {
+  return invoke("get_version_changelog", { args: input });
+}
+
+export function checkVersionMigration(input: {
+  instanceId: string;
+  targetMcVersion: string;
+}): Promise<VersionMigrationReport> {
+  return invoke("check_version_migration", { args: input });
+}
+
+export function migrateInstanceVersion(input: {
+  instanceId: string;
+  targetMcVersion: string;
+  targetLoader?: string;
+  requiredProjectIds?: string[];
+}): Promise<MigrateInstanceVersionResult> {
+  return invoke("migrate_instance_version", { args: input });
+}
+
 export function updateAllModrinthMods(input: {
   instanceId: string;
 }): Promise<UpdateAllResult> {
@@ -169,13 +444,31 @@ export function searchDiscoverContent(input: {
   limit: number;
   offset: number;
   source: DiscoverSource;
-  contentType: DiscoverContentType;
+  /** "all" searches every content type; each hit carries its own content_type. */
+  contentType: DiscoverContentType | "all";
+  /** Provider order used to pick between duplicates when source is "all". */
+  providerPriority?: string[];
+  /** Lets cancelDiscoverSearch abandon this search; the result then has cancelled set. */
+  searchId?: string;
 }): Promise<DiscoverSearchResult> {
   return invoke("search_discover_content", { args: input });
 }
 
+export function cancelDiscoverSearch(input: { searchId: string }): Promise<void> {
+  return invoke("cancel_discover_search", { args: input });
+}
+
+export function listDiscoverCategories(input: {
+  contentType: DiscoverContentType;
+}): Promise<DiscoverCategory[]> {
+  return invoke("list_discover_categories", { args: input });
+}
+
 export function getCurseforgeProjectDetail(input: {
   projectId: string;
+  gameVersion?: string;
+  loader?: string;
+  limit?: number;
 }): Promise<CurseforgeProjectDetail> {
   return invoke("get_curseforge_project_detail", { args: input });
 }
@@ -187,6 +480,31 @@ export function launchInstance(input: {
   return invoke("launch_instance", { args: input });
 }
 
+export function dryRunLaunch(instanceId: string): Promise<DryRunLaunchResult> {
+  return invoke("dry_run_launch", { args: { instanceId } });
+}
+
+/** Re-resolves the instance's loader version, skipping the cached value. */
+export function refreshLoaderVersion(instanceId: string): Promise<string | null> {
+  return invoke("refresh_loader_version", { args: { instanceId } });
+}
+
+export function listLoaderVersions(instanceId: string): Promise<string[]> {
+  return invoke("list_loader_versions", { args: { instanceId } });
+}
+
+export function setInstanceLoaderVersion(input: {
+  instanceId: string;
+  loaderVersion: string | null;
+}): Promise<Instance> {
+  return invoke("set_instance_loader_version", { args: input });
+}
+
+/** Re-verifies and re-downloads the vanilla client, libraries and assets. */
+export function repairInstanceRuntime(instanceId: string): Promise<RepairInstanceRuntimeResult> {
+  return invoke("repair_instance_runtime", { args: { instanceId } });
+}
+
 export function getLauncherSettings(): Promise<LauncherSettings> {
   return invoke("get_launcher_settings");
 }
@@ -195,6 +513,10 @@ export function getCurseforgeApiStatus(): Promise<CurseforgeApiStatus> {
   return invoke("get_curseforge_api_status");
 }
 
+export function setCurseforgeApiKey(input: { apiKey: string }): Promise<CurseforgeApiStatus> {
+  return invoke("set_curseforge_api_key", { args: input });
+}
+
 export function setLauncherSettings(input: {
   defaultLaunchMethod?: LaunchMethod;
   javaPath?: string;
@@ -202,14 +524,40 @@ export function setLauncherSettings(input: {
   updateCheckCadence?: "off" | "hourly" | "every_3_hours" | "every_6_hours" | "every_12_hours" | "daily" | "weekly";
   updateAutoApplyMode?: "never" | "opt_in_instances" | "all_instances";
   updateApplyScope?: "scheduled_only" | "scheduled_and_manual";
+  backupCompression?: "none" | "fast" | "balanced" | "max";
+  worldBackupMode?: "full" | "incremental";
+  safeUpdateMode?: "off" | "prompt" | "auto_rollback";
+  crashReportRetentionCount?: number;
+  prismAccountPassthrough?: boolean;
 }): Promise<LauncherSettings> {
   return invoke("set_launcher_settings", { args: input });
 }
 
+export function saveInstanceSettingsAsDefault(input: {
+  instanceId: string;
+}): Promise<LauncherSettings> {
+  return invoke("save_instance_settings_as_default", { args: input });
+}
+
+export function getDiscoverPreferences(): Promise<Record<string, DiscoverPreferences>> {
+  return invoke("get_discover_preferences");
+}
+
+export function setDiscoverPreferences(input: {
+  contentType: string;
+  preferences: DiscoverPreferences | null;
+}): Promise<Record<string, DiscoverPreferences>> {
+  return invoke("set_discover_preferences", { args: input });
+}
+
 export function listLauncherAccounts(): Promise<LauncherAccount[]> {
   return invoke("list_launcher_accounts");
 }
 
+export function migrateLegacyCredentials(): Promise<MigrateLegacyCredentialsResult> {
+  return invoke("migrate_legacy_credentials");
+}
+
 export function beginMicrosoftLogin(): Promise<BeginMicrosoftLoginResult> {
   return invoke("begin_microsoft_login");
 }
@@ -248,17 +596,49 @@ export function cancelInstanceLaunch(input: {
   return invoke("cancel_instance_launch", { args: input });
 }
 
+export function suggestExportFilename(input: {
+  instanceId: string;
+  directory?: string;
+  serverOnly?: boolean;
+}): Promise<SuggestExportFilenameResult> {
+  return invoke("suggest_export_filename", { args: input });
+}
+
 export function exportInstanceModsZip(input: {
   instanceId: string;
+  /** A file path, or a directory to receive the suggested filename. */
   outputPath?: string;
+  onlyEnabled?: boolean;
+  includeResourcepacks?: boolean;
+  includeShaderpacks?: boolean;
+  serverOnly?: boolean;
 }): Promise<ExportModsResult> {
   return invoke("export_instance_mods_zip", { args: input });
 }
 
+export function exportInstanceBundle(input: {
+  instanceId: string;
+  outputPath?: string;
+  serverOnly?: boolean;
+}): Promise<ExportModsResult> {
+  return invoke("export_instance_bundle", { args: input });
+}
+
+export function importInstanceBundle(input: {
+  filePath: string;
+  name?: string;
+}): Promise<ImportInstanceBundleResult> {
+  return invoke("import_instance_bundle", { args: input });
+}
+
 export function getSelectedAccountDiagnostics(): Promise<AccountDiagnostics> {
   return invoke("get_selected_account_diagnostics");
 }
 
+export function refreshAllAccountDiagnostics(): Promise<AccountDiagnostics[]> {
+  return invoke("refresh_all_account_diagnostics");
+}
+
 export function openInstancePath(input: {
   instanceId: string;
   target:
@@ -273,6 +653,20 @@ export function openInstancePath(input: {
   return invoke("open_instance_path", { args: input });
 }
 
+export function openInstanceSubpath(input: {
+  instanceId: string;
+  path: string;
+}): Promise<OpenInstanceSubpathResult> {
+  return invoke("open_instance_subpath", { args: input });
+}
+
+export function setActiveShader(input: {
+  instanceId: string;
+  filename?: string | null;
+}): Promise<SetActiveShaderResult> {
+  return invoke("set_active_shader", { args: input });
+}
+
 export function revealConfigEditorFile(input: {
   instanceId: string;
   scope: "instance" | "world";
@@ -284,13 +678,29 @@ export function revealConfigEditorFile(input: {
 
 export function readInstanceLogs(input: {
   instanceId: string;
-  source: "live" | "latest_launch" | "latest_crash";
+  source: InstanceLogSourceApi;
   maxLines?: number;
   beforeLine?: number;
+  /** Required when source is "crash_report"; a path from listCrashReports. */
+  crashReportPath?: string;
+  /** Required when source is "rotated"; a file name from listRotatedLogs. */
+  logFile?: string;
 }): Promise<ReadInstanceLogsResult> {
   return invoke("read_instance_logs", { args: input });
 }
 
+export function listCrashReports(instanceId: string): Promise<CrashReportEntry[]> {
+  return invoke("list_crash_reports", { args: { instanceId } });
+}
+
+export function listRotatedLogs(instanceId: string): Promise<RotatedLogEntry[]> {
+  return invoke("list_rotated_logs", { args: { instanceId } });
+}
+
+export function pruneInstanceCrashReports(input: { instanceId: string; keep?: number }): Promise<number> {
+  return invoke("prune_instance_crash_reports", { args: input });
+}
+
 export function listInstanceSnapshots(input: {
   instanceId: string;
 }): Promise<SnapshotMeta[]> {
@@ -304,6 +714,14 @@ export function rollbackInstance(input: {
   return invoke("rollback_instance", { args: input });
 }
 
+export function restoreSnapshotFile(input: {
+  instanceId: string;
+  snapshotId: string;
+  path: string;
+}): Promise<RestoreSnapshotFileResult> {
+  return invoke("restore_snapshot_file", { args: input });
+}
+
 export function rollbackInstanceWorldBackup(input: {
   instanceId: string;
   worldId: string;
@@ -312,12 +730,59 @@ export function rollbackInstanceWorldBackup(input: {
   return invoke("rollback_instance_world_backup", { args: input });
 }
 
+export function cleanupWorldBackups(input: {
+  instanceId: string;
+  worldId?: string;
+}): Promise<CleanupWorldBackupsResult> {
+  return invoke("cleanup_world_backups", { args: input });
+}
+
+export function verifyWorldBackup(input: {
+  instanceId: string;
+  backupId: string;
+}): Promise<BackupVerificationResult> {
+  return invoke("verify_world_backup", { args: input });
+}
+
+export function verifySnapshot(input: {
+  instanceId: string;
+  snapshotId: string;
+}): Promise<BackupVerificationResult> {
+  return invoke("verify_snapshot", { args: input });
+}
+
 export function listInstanceWorlds(input: {
   instanceId: string;
 }): Promise<InstanceWorld[]> {
   return invoke("list_instance_worlds", { args: input });
 }
 
+export function copyWorldToInstance(input: {
+  fromInstanceId: string;
+  worldId: string;
+  toInstanceId: string;
+  newName?: string;
+}): Promise<InstanceWorld> {
+  return invoke("copy_world_to_instance", { args: input });
+}
+
+export function exportWorld(input: {
+  instanceId: string;
+  worldId: string;
+  outputPath: string;
+  includeDatapacks?: boolean;
+}): Promise<ExportWorldResult> {
+  return invoke("export_world", { args: input });
+}
+
+export function importWorld(input: {
+  instanceId: string;
+  zipPath: string;
+  newName?: string;
+}): Promise<InstanceWorld> {
+  return invoke("import_world", { args: input });
+}
+
 export function listWorldConfigFiles(input: {
   instanceId: string;
   worldId: string;
@@ -354,6 +819,19 @@ export function installDiscoverContent(input: {
   return invoke("install_discover_content", { args: input });
 }
 
+export function installDiscoverContentBatch(input: {
+  instanceId: string;
+  entries: Array<{
+    source: DiscoverSource | "modrinth" | "curseforge";
+    projectId: string;
+    projectTitle?: string;
+    contentType: DiscoverContentType;
+    targetWorlds?: string[];
+  }>;
+}): Promise<InstallDiscoverContentBatchResult> {
+  return invoke("install_discover_content_batch", { args: input });
+}
+
 export function previewPresetApply(input: {
   instanceId: string;
   preset: CreatorPreset;
@@ -388,3 +866,22 @@ export function importPresetsJson(input: {
 }): Promise<unknown> {
   return invoke("import_presets_json", { args: input });
 }
+
+export function encodePresetShareCode(input: {
+  preset: CreatorPreset;
+}): Promise<string> {
+  return invoke("encode_preset_share_code", { args: input });
+}
+
+export function decodePresetShareCode(input: {
+  code: string;
+}): Promise<CreatorPreset> {
+  return invoke("decode_preset_share_code", { args: input });
+}
+
+export function diffInstances(input: {
+  instanceAId: string;
+  instanceBId: string;
+}): Promise<InstanceDiffResult> {
+  return invoke("diff_instances", { args: input });
+}
@@ -1,5 +1,22 @@
 export type Loader = "fabric" | "forge" | "quilt" | "neoforge" | "vanilla";
 
+export type AppErrorCode =
+  | "network"
+  | "auth"
+  | "not_found"
+  | "validation"
+  | "io"
+  | "provider"
+  | "rate_limited"
+  | "cancelled"
+  | "unknown";
+
+export type AppErrorPayload = {
+  code: AppErrorCode;
+  message: string;
+  hint?: string | null;
+};
+
 export type Instance = {
   id: string;
   name: string;
@@ -8,6 +25,25 @@ export type Instance = {
   created_at: string;
   icon_path?: string | null;
   settings?: InstanceSettings;
+  tags?: string[];
+  is_favorite?: boolean;
+  last_played_at?: string | null;
+  total_play_seconds?: number;
+  sort_order?: number | null;
+  /** Pinned loader build; unset resolves the latest at launch. */
+  loader_version?: string | null;
+};
+
+export type ReadLocalImageDataUrlResult = {
+  data_url: string;
+  encoded_bytes: number;
+  downscaled: boolean;
+};
+
+export type SetInstanceIconResult = {
+  instance: Instance;
+  icon_width?: number | null;
+  icon_height?: number | null;
 };
 
 export type InstanceSettings = {
@@ -21,9 +57,14 @@ export type InstanceSettings = {
   jvm_args: string;
   graphics_preset: "Performance" | "Balanced" | "Quality" | string;
   enable_shaders: boolean;
+  /** Set once shaders are toggled in OpenJar; until then launch keeps the in-game choice. */
+  shaders_managed: boolean;
   force_vsync: boolean;
   world_backup_interval_minutes: number;
   world_backup_retention_count: number;
+  account_id_override: string | null;
+  /** Quilt instances only: install Fabric builds when a project has no Quilt build. */
+  allow_fabric_on_quilt: boolean;
 };
 
 export type InstalledMod = {
@@ -38,8 +79,17 @@ export type InstalledMod = {
   target_worlds?: string[];
   pinned_version?: string | null;
   enabled: boolean;
+  enabled_state?: "enabled" | "disabled" | "partial" | string;
+  disabled_worlds?: string[];
   file_exists: boolean;
   hashes?: Record<string, string>;
+  /** Loader the installed build targets when it differs from the instance's, e.g. "fabric" on Quilt. */
+  loader_fallback?: string | null;
+};
+
+export type ImportLocalModFileResult = {
+  installed: InstalledMod;
+  warning?: string | null;
 };
 
 export type InstallProgressEvent = {
@@ -52,19 +102,53 @@ export type InstallProgressEvent = {
   message?: string | null;
 };
 
+export type NativeDownloadProgressEvent = {
+  instance_id: string;
+  stage: "assets" | "libraries";
+  files_done: number;
+  files_total?: number | null;
+  bytes_done?: number | null;
+  bytes_total?: number | null;
+  percent?: number | null;
+  current_file?: string | null;
+};
+
 export type InstallPlanPreview = {
   total_mods: number;
   dependency_mods: number;
   will_install_mods: number;
 };
 
+export type InstallSizeFile = {
+  project_id: string;
+  version_id: string;
+  filename: string;
+  size_bytes: number;
+  dependency: boolean;
+  already_installed: boolean;
+};
+
+export type InstallSizeEstimate = {
+  /** Bytes the install would download; already installed files are left out. */
+  total_bytes: number;
+  files: InstallSizeFile[];
+};
+
 export type ModUpdateInfo = {
+  source?: "modrinth" | "curseforge" | string;
   project_id: string;
   name: string;
   current_version_id: string;
   current_version_number: string;
   latest_version_id: string;
   latest_version_number: string;
+  changelog?: string | null;
+};
+
+export type VersionChangelog = {
+  version_id: string;
+  changelog: string;
+  truncated: boolean;
 };
 
 export type ModUpdateCheckResult = {
@@ -73,11 +157,88 @@ export type ModUpdateCheckResult = {
   updates: ModUpdateInfo[];
 };
 
+export type InstanceUpdateCheckEntry = {
+  instance_id: string;
+  instance_name: string;
+  result?: ModUpdateCheckResult | null;
+  error?: string | null;
+};
+
+export type CheckUpdatesAllInstancesResult = {
+  results: InstanceUpdateCheckEntry[];
+  total_updates: number;
+  cancelled: boolean;
+  remaining_instance_ids: string[];
+};
+
+export type UpdateScanProgressEvent = {
+  checked: number;
+  total: number;
+  instance_id: string;
+  instance_name: string;
+  update_count: number;
+  error?: string | null;
+};
+
+export type VersionMigrationEntry = {
+  source: string;
+  project_id: string;
+  name: string;
+  content_type: string;
+  current_version_id: string;
+  current_version_number: string;
+  status: "already_on_target" | "available" | "unavailable" | "unknown" | string;
+  target_version_id?: string | null;
+  target_version_number?: string | null;
+  message?: string | null;
+};
+
+export type VersionMigrationReport = {
+  current_mc_version: string;
+  target_mc_version: string;
+  checked_entries: number;
+  available_count: number;
+  unavailable_count: number;
+  entries: VersionMigrationEntry[];
+};
+
+export type VersionMigrationFailure = {
+  project_id: string;
+  name: string;
+  reason: string;
+  required: boolean;
+  disabled: boolean;
+};
+
+export type MigrateInstanceVersionResult = {
+  applied: boolean;
+  snapshot_id: string;
+  mc_version: string;
+  loader: string;
+  migrated: string[];
+  unchanged: number;
+  skipped_local: number;
+  failed: VersionMigrationFailure[];
+  message: string;
+};
+
 export type UpdateAllResult = {
   checked_mods: number;
   updated_mods: number;
 };
 
+export type DryRunLaunchStep = {
+  step: "java" | "account" | "loader" | "runtime";
+  ok: boolean;
+  message: string;
+};
+
+export type DryRunLaunchResult = {
+  instance_id: string;
+  ready: boolean;
+  steps: DryRunLaunchStep[];
+};
+
 export type LaunchResult = {
   method: "prism" | "native" | string;
   launch_id?: string | null;
@@ -102,6 +263,9 @@ export type UpdateCheckCadence =
 export type UpdateAutoApplyMode = "never" | "opt_in_instances" | "all_instances" | string;
 
 export type UpdateApplyScope = "scheduled_only" | "scheduled_and_manual" | string;
+export type BackupCompression = "none" | "fast" | "balanced" | "max" | string;
+export type WorldBackupMode = "full" | "incremental" | string;
+export type SafeUpdateMode = "off" | "prompt" | "auto_rollback" | string;
 
 export type LauncherSettings = {
   default_launch_method: LaunchMethod;
@@ -110,7 +274,51 @@ export type LauncherSettings = {
   update_check_cadence: UpdateCheckCadence;
   update_auto_apply_mode?: UpdateAutoApplyMode;
   update_apply_scope?: UpdateApplyScope;
+  backup_compression?: BackupCompression;
+  world_backup_mode?: WorldBackupMode;
+  safe_update_mode?: SafeUpdateMode;
   selected_account_id?: string | null;
+  instances_root_override?: string | null;
+  crash_report_retention_count: number;
+  default_instance_settings?: InstanceSettings;
+  discover_preferences?: Record<string, DiscoverPreferences>;
+  prism_account_passthrough?: boolean;
+};
+
+/** Last-used discover filters for one content type. */
+export type DiscoverPreferences = {
+  loaders?: string[];
+  categories?: string[];
+  game_version?: string | null;
+  index?: string;
+};
+
+export type MoveInstancesRootResult = {
+  from: string;
+  to: string;
+  moved_bytes: number;
+  message: string;
+  warning?: string | null;
+};
+
+export type LauncherCacheEntry = {
+  name: string;
+  path: string;
+  bytes: number;
+};
+
+export type LauncherCacheStats = {
+  path: string;
+  total_bytes: number;
+  entries: LauncherCacheEntry[];
+  running_instances: number;
+  warning?: string | null;
+};
+
+export type ClearLauncherCacheResult = {
+  freed_bytes: number;
+  cleared: string[];
+  message: string;
 };
 
 export type LauncherAccount = {
@@ -127,6 +335,15 @@ export type AccountCosmeticSummary = {
   variant?: string | null;
 };
 
+export type MigrateLegacyCredentialsResult = {
+  checked_accounts: number;
+  migrated: number;
+  already_canonical: number;
+  missing: number;
+  removed_legacy_entries: number;
+  errors: string[];
+};
+
 export type AccountDiagnostics = {
   status: "connected" | "not_connected" | "error" | string;
   last_refreshed_at: string;
@@ -169,6 +386,8 @@ export type MicrosoftLoginState = {
   status: "pending" | "success" | "error" | string;
   message?: string | null;
   account?: LauncherAccount | null;
+  expires_at?: number | null;
+  seconds_remaining?: number | null;
 };
 
 export type RunningInstance = {
@@ -181,7 +400,84 @@ export type RunningInstance = {
   log_path?: string | null;
 };
 
-export type InstanceLogSourceApi = "live" | "latest_launch" | "latest_crash";
+export type InstanceLogSourceApi = "live" | "latest_launch" | "latest_crash" | "crash_report" | "rotated";
+
+export type DatapackDriftEntry = {
+  version_id: string;
+  name: string;
+  filename: string;
+  worlds: string[];
+};
+
+export type SyncWorldDatapacksResult = {
+  instance_id: string;
+  worlds_scanned: number;
+  added: DatapackDriftEntry[];
+  linked: DatapackDriftEntry[];
+  missing: DatapackDriftEntry[];
+};
+
+export type MissingDependency = {
+  dependent_version_id: string;
+  dependent_project_id: string;
+  dependent_name: string;
+  project_id: string;
+  version_id?: string | null;
+  name: string;
+  status: "missing" | "disabled" | "unverified";
+};
+
+export type CheckMissingDependenciesResult = {
+  instance_id: string;
+  checked: number;
+  missing: MissingDependency[];
+};
+
+export type InstallMissingDependenciesResult = {
+  instance_id: string;
+  enabled: InstalledMod[];
+  installed: InstalledMod[];
+  failed: string[];
+};
+
+export type PruneMissingDatapackTargetsResult = {
+  instance_id: string;
+  pruned: DatapackDriftEntry[];
+  removed: DatapackDriftEntry[];
+  deleted_files: string[];
+};
+
+export type RepairInstanceRuntimeResult = {
+  mc_version: string;
+  checked_files: number;
+  repaired: string[];
+  missing: number;
+};
+
+export type SuggestExportFilenameResult = {
+  filename: string;
+  path: string;
+};
+
+export type IdentifyLocalContentResult = {
+  instance_id: string;
+  checked: number;
+  identified: InstalledMod[];
+  unidentified: string[];
+};
+
+export type CrashReportEntry = {
+  path: string;
+  file_name: string;
+  modified_at: number;
+  size_bytes: number;
+};
+
+export type RotatedLogEntry = {
+  file_name: string;
+  modified_at: number;
+  size_bytes: number;
+};
 
 export type ReadInstanceLogsLine = {
   raw: string;
@@ -206,9 +502,45 @@ export type ReadInstanceLogsResult = {
   message?: string | null;
 };
 
+export type ContentRepairFailure = {
+  source: string;
+  project_id: string;
+  version_id: string;
+  name: string;
+  reason: string;
+};
+
+export type RepairInstanceContentResult = {
+  checked_entries: number;
+  missing_entries: number;
+  repaired: number;
+  repaired_names: string[];
+  failed: ContentRepairFailure[];
+};
+
+export type OrphanedContentFile = {
+  content_type: string;
+  filename: string;
+  relative_path: string;
+  size_bytes: number;
+};
+
+export type FindOrphanedContentResult = {
+  orphans: OrphanedContentFile[];
+  local_tracked_files: number;
+};
+
+export type RemoveOrphanedContentResult = {
+  removed: string[];
+  skipped: string[];
+  snapshot_id?: string | null;
+};
+
 export type ExportModsResult = {
   output_path: string;
   files_count: number;
+  excluded_client_only?: string[];
+  unknown_side?: string[];
 };
 
 export type OpenInstancePathResult = {
@@ -224,6 +556,19 @@ export type OpenInstancePathResult = {
   path: string;
 };
 
+export type OpenInstanceSubpathResult = {
+  path: string;
+  opened_path: string;
+  revealed_file: boolean;
+};
+
+export type SetActiveShaderResult = {
+  shader_loader: "iris" | "oculus" | "optifine";
+  shader_pack?: string | null;
+  config_path: string;
+  message: string;
+};
+
 export type RevealConfigEditorFileResult = {
   opened_path: string;
   revealed_file: boolean;
@@ -231,12 +576,40 @@ export type RevealConfigEditorFileResult = {
   message: string;
 };
 
+export type ModpackFileInfo = {
+  format: "modrinth" | "curseforge" | "overrides";
+  name: string;
+  mc_version: string | null;
+  loader: Loader;
+  override_roots: string[];
+  warnings: string[];
+};
+
 export type CreateInstanceFromModpackFileResult = {
   instance: Instance;
   imported_files: number;
   warnings: string[];
 };
 
+export type CurseforgeManualDownload = {
+  project_id: string;
+  file_id: number;
+  name: string;
+  file_name: string;
+  /** CurseForge project page the file can be downloaded from. */
+  page_url: string;
+  target_dir: string;
+  reason: string;
+};
+
+export type InstallModpackResult = {
+  instance: Instance;
+  installed_files: number;
+  imported_files: number;
+  warnings: string[];
+  manual_downloads: CurseforgeManualDownload[];
+};
+
 export type LauncherImportSource = {
   id: string;
   source_kind: "vanilla" | "prism" | string;
@@ -246,9 +619,42 @@ export type LauncherImportSource = {
   source_path: string;
 };
 
+export type DiagnosticCheck = {
+  ok: boolean;
+  message: string;
+};
+
+export type DiagnosticsReport = {
+  generated_at: string;
+  app_version: string;
+  os: string;
+  arch: string;
+  app_data_dir?: string | null;
+  instances_index: DiagnosticCheck;
+  instance_count: number;
+  keyring: DiagnosticCheck;
+  curseforge: CurseforgeApiStatus;
+  modrinth: DiagnosticCheck;
+  java_runtimes: JavaRuntimeCandidate[];
+  import_sources: LauncherImportSource[];
+};
+
+export type ImportInstanceBundleResult = {
+  instance: Instance;
+  imported_files: number;
+};
+
 export type ImportInstanceFromLauncherResult = {
   instance: Instance;
   imported_files: number;
+  warnings: string[];
+};
+
+export type ExportWorldResult = {
+  output_path: string;
+  files: number;
+  bytes: number;
+  zip_bytes: number;
 };
 
 export type InstanceWorld = {
@@ -258,6 +664,11 @@ export type InstanceWorld = {
   latest_backup_id?: string | null;
   latest_backup_at?: string | null;
   backup_count?: number;
+  game_mode?: "survival" | "creative" | "adventure" | "spectator" | "hardcore" | null;
+  difficulty?: "peaceful" | "easy" | "normal" | "hard" | null;
+  seed?: string | null;
+  last_played?: number | null;
+  version?: string | null;
 };
 
 export type WorldConfigFileEntry = {
@@ -300,6 +711,13 @@ export type RollbackResult = {
   message: string;
 };
 
+export type RestoreSnapshotFileResult = {
+  snapshot_id: string;
+  path: string;
+  bytes: number;
+  message: string;
+};
+
 export type WorldRollbackResult = {
   world_id: string;
   backup_id: string;
@@ -308,6 +726,24 @@ export type WorldRollbackResult = {
   message: string;
 };
 
+export type CleanupWorldBackupsResult = {
+  removed_backups: number;
+  removed_backup_ids: string[];
+  freed_bytes: number;
+  message: string;
+};
+
+export type BackupVerificationResult = {
+  id: string;
+  ok: boolean;
+  expected_files?: number | null;
+  actual_files: number;
+  expected_bytes?: number | null;
+  actual_bytes: number;
+  mismatches: string[];
+  message: string;
+};
+
 export type DiscoverSource = "modrinth" | "curseforge" | "all";
 export type DiscoverContentType = "mods" | "shaderpacks" | "resourcepacks" | "datapacks" | "modpacks";
 
@@ -333,6 +769,16 @@ export type DiscoverSearchResult = {
   offset: number;
   limit: number;
   total_hits: number;
+  end_reached?: boolean;
+  /** Set when the search was cancelled by a newer one; ignore the result. */
+  cancelled?: boolean;
+};
+
+export type DiscoverCategory = {
+  slug: string;
+  name: string;
+  header: string;
+  icon_svg?: string | null;
 };
 
 export type CurseforgeProjectFileDetail = {
@@ -358,6 +804,7 @@ export type CurseforgeProjectDetail = {
   date_modified: string;
   external_url?: string | null;
   files: CurseforgeProjectFileDetail[];
+  total_files: number;
 };
 
 export type PresetsJsonIoResult = {
@@ -395,6 +842,24 @@ export type CreatorPreset = {
   settings?: CreatorPresetSettings;
 };
 
+export type InstanceDiffEntry = {
+  source: string;
+  project_id: string;
+  name: string;
+  content_type: string;
+  version_a?: string | null;
+  version_b?: string | null;
+};
+
+export type InstanceDiffResult = {
+  instance_a_id: string;
+  instance_b_id: string;
+  only_in_a: InstanceDiffEntry[];
+  only_in_b: InstanceDiffEntry[];
+  version_mismatch: InstanceDiffEntry[];
+  only_in_a_preset: CreatorPreset;
+};
+
 export type PresetApplyPreview = {
   valid: boolean;
   installable_entries: number;
@@ -402,6 +867,30 @@ export type PresetApplyPreview = {
   missing_world_targets: string[];
   provider_warnings: string[];
   duplicate_entries: number;
+  dependencies_resolved?: boolean;
+  total_unique_items?: number;
+  dependency_items?: number;
+  unresolved_entries?: string[];
+  estimated_download_bytes?: number;
+};
+
+export type InstallDiscoverBatchEntryResult = {
+  source: string;
+  project_id: string;
+  content_type: string;
+  ok: boolean;
+  installed?: InstalledMod | null;
+  error?: string | null;
+  manual_downloads: CurseforgeManualDownload[];
+};
+
+export type InstallDiscoverContentBatchResult = {
+  message: string;
+  installed_entries: number;
+  failed_entries: number;
+  snapshot_id?: string | null;
+  by_content_type: Record<string, number>;
+  results: InstallDiscoverBatchEntryResult[];
 };
 
 export type PresetApplyResult = {
@@ -411,4 +900,5 @@ export type PresetApplyResult = {
   failed_entries: number;
   snapshot_id?: string | null;
   by_content_type: Record<string, number>;
+  manual_downloads: CurseforgeManualDownload[];
 };